@@ -3,11 +3,71 @@
 //! of a string of text in Arabic or Persian (support for other Arabic-script
 //! languages may be added over time).
 //!
-//! At the moment, this simply adds three methods for `&str`:
+//! At the moment, this simply adds six methods for `&str` (and for `String`,
+//! `Cow<str>`, `char`, and, wrapped in `Chars`, any iterator of `char`s):
 //!
 //! - `abjad` returns a best-effort value, ignoring unrecognized characters.
 //! - `abjad_collect_errors` also records unrecognized characters in a `Vec`.
 //! - `abjad_strict` returns an error as soon as any character is not recognized.
+//! - `abjad_checked` is like `abjad`, but returns `None` if the total overflows.
+//! - `abjad_wide` is like `abjad`, but sums into a `u64`.
+//! - `abjad_breakdown` returns a per-character trace of how the total was reached.
+//!
+//! Totals are `u32` unless otherwise noted. `abjad`, `abjad_collect_errors`, and
+//! `abjad_breakdown` saturate at `u32::MAX` rather than overflowing; this would
+//! take more than four million instances of _ghayn_, but it can happen with
+//! book-length input.
+//!
+//! For input too large to hold in memory, `AbjadAccumulator` values text fed in
+//! chunks, or read from an `io::Read`.
+//!
+//! Values follow the Mashriqi or Maghribi letter order, or a custom
+//! `AbjadTable`, which may be parsed from a simple text (or TOML or JSON) format.
+//! Settings for common traditions are available as a `Preset`, and
+//! `AbjadPrefs::builder` checks that custom settings are consistent.
+//!
+//! Letters written with a combining _maddah_ or _hamzah_ (U+0653–U+0655) are
+//! composed before they are valued, as in NFC (and, as typed on Persian
+//! keyboards, ه with _hamzah_ as ۀ), so that input gives the same total whether
+//! it is composed or decomposed. The Arabic and Persian forms of _ya'_, and
+//! _alif maqsurah_, are all valued as ي.
+//!
+//! In the other direction, `to_abjad_numeral` writes a number in _abjad_ notation,
+//! and `parse_abjad_numeral` reads it back. (This is not the same as summing the
+//! values of the letters: in descending order, ثغ is 500 × 1,000.)
+//!
+//! Romanized text (in IJMES, ALA-LC, or DMG transliteration) can be converted to
+//! Arabic script with `transliterate`, which reports any ambiguous sequences.
+//!
+//! Conversely, `letter_label` gives the name and romanized symbol of
+//! a letter (e.g., ص as ṣād, ṣ), and `BreakdownEntry::label` does the same for
+//! each entry of a breakdown.
+//!
+//! `Reduction` offers reductions of totals by various moduli (such as 12 for
+//! _abjad saghir_, and 28 for the letters and lunar mansions).
+//!
+//! `AbjadWords` splits a text into words, yielding the value of each.
+//!
+//! To help with composing chronograms, `ChronogramSearch` finds combinations of
+//! words from a lexicon that add up to a given value; and `SpanScan` finds spans
+//! of consecutive words in a text that do.
+//!
+//! With the `serde` feature, preferences, errors, and results can be serialized
+//! (and deserialized, except for types borrowing from the input, and `Reduced`
+//! and `LetterLabel`, which hold names from the crate's own tables). Field names
+//! are those of the Rust types, and `enum` variants are written in snake case
+//! (e.g., `"mashriqi"`, or `"alif_maddah"`), except that presets, calculation
+//! modes, and schemes of romanization are written as they are parsed and
+//! displayed (e.g., `"persian-chronogram"`, `"malfuzi-hamzated"`, `"ala-lc"`).
+//! These names are part of the public API and will not change without a major
+//! version. A table is written as its 28 values, in Mashriqi order. When
+//! deserializing `AbjadPrefs`, `NumeralPrefs`, or `SearchPrefs`, missing fields
+//! take their default values, so that stored settings remain readable as options
+//! are added.
+//!
+//! With the `cli` feature, the crate also builds an `abjad` binary, which exposes
+//! these options on the command line (see `abjad --help`), and can print totals,
+//! per-word and per-letter values, and errors as text, JSON, or CSV.
 //!
 
 #![forbid(unsafe_code)]
@@ -15,26 +75,171 @@
 #![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
 #![allow(clippy::too_long_first_doc_paragraph)]
 
+use std::borrow::Cow;
+use std::fmt;
+
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::UnicodeScript;
 
-/// The error type for this crate. Currently there is only one member:
-/// `UnrecognizedCharacter`, which is returned by `abjad_strict` upon encountering
-/// any character outside of the Arabic script.
+use table::letter_index;
+
+mod names;
+mod numeral;
+mod prefs;
+mod reduce;
+mod romanize;
+mod scan;
+mod search;
+mod stream;
+mod table;
+mod translit;
+mod words;
+
+pub use names::NameSpelling;
+pub use numeral::{
+    parse_abjad_numeral, to_abjad_numeral, ComponentOrder, NumeralFault, NumeralPrefs,
+    ThousandsStyle,
+};
+pub use prefs::{AbjadPrefsBuilder, Preset};
+pub use reduce::{Reduced, Reduction};
+pub use romanize::{letter_label, LetterLabel};
+pub use scan::{Span, SpanScan};
+pub use search::{ChronogramSearch, SearchPrefs};
+pub use stream::AbjadAccumulator;
+pub use table::AbjadTable;
+pub use translit::{transliterate, Ambiguity, Romanization, Transliteration};
+pub use unicode_general_category::GeneralCategory;
+pub use unicode_script::Script;
+pub use words::{AbjadWords, Word};
+
+/// The error type for this crate. `abjad_strict` returns `UnrecognizedCharacter`
+/// upon encountering any character outside of the Arabic script, and `Overflow`
+/// if the total grows too large. `parse_abjad_numeral` returns `MalformedNumeral`
+/// (or `Overflow`) if its input cannot be read as a number. Parsing an
+/// `AbjadTable` returns `InvalidTable` if something is wrong; parsing a name
+/// returns `UnknownName`; and building `AbjadPrefs` returns `InvalidPrefs`.
+/// `AbjadAccumulator` returns `InvalidUtf8` if a stream of bytes is malformed.
 #[derive(Error, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum AbjadError {
     /// This error is returned by `abjad_strict` upon encountering any character
-    /// outside of the Arabic script. It reports the Unicode escape sequence for
-    /// the character in question.
+    /// outside of the Arabic script. It reports the character in question and
+    /// its position in the input.
     #[error("Unrecognized character: {0}")]
-    UnrecognizedCharacter(String),
+    UnrecognizedCharacter(UnrecognizedChar),
+
+    /// This error is returned by `abjad_strict` if the total would exceed
+    /// `u32::MAX`. It reports the position of the character at which this
+    /// happened. (For very long inputs, consider `abjad_wide` instead.)
+    #[error("Overflow: total exceeds u32::MAX at byte {byte_offset} (char {char_index})")]
+    Overflow {
+        /// The byte offset of the character that caused the overflow
+        byte_offset: usize,
+        /// The index of the character that caused the overflow, counted in `char`s
+        char_index: usize,
+    },
+
+    /// This error is returned by `parse_abjad_numeral` if its input is not a
+    /// well-formed number in _abjad_ notation. It reports what is wrong, and the
+    /// position of the character at which the problem was found.
+    #[error("Malformed abjad numeral: {fault} at byte {byte_offset} (char {char_index})")]
+    MalformedNumeral {
+        /// What is wrong with the numeral
+        fault: NumeralFault,
+        /// The byte offset of the character at which the problem was found
+        byte_offset: usize,
+        /// The index of the character at which the problem was found, counted
+        /// in `char`s
+        char_index: usize,
+    },
+
+    /// This error is returned by `AbjadAccumulator` if its input is not valid
+    /// UTF-8. It reports the byte offset in the stream at which this was found.
+    #[error("Invalid UTF-8 at byte {byte_offset}")]
+    InvalidUtf8 {
+        /// The byte offset of the invalid sequence
+        byte_offset: usize,
+    },
+
+    /// This error is returned when the name of a `Preset`, or of a `LetterOrder`
+    /// or other option, cannot be parsed. It reports the name in question.
+    #[error("Unknown name: {0}")]
+    UnknownName(String),
+
+    /// This error is returned by `AbjadPrefsBuilder::build` if the preferences do
+    /// not make sense together. It reports the reason.
+    #[error("Invalid preferences: {0}")]
+    InvalidPrefs(String),
+
+    /// This error is returned when a custom `AbjadTable` cannot be parsed. It
+    /// reports the line number (starting from 1) and the offending line.
+    #[error("Invalid table entry at line {line}: {entry}")]
+    InvalidTable {
+        /// The line number, starting from 1
+        line: usize,
+        /// The text of the line
+        entry: String,
+    },
+}
+
+/// A record of an unrecognized character: the character itself and where it
+/// was found. These are collected by `abjad_collect_errors`, and one is carried
+/// by `AbjadError::UnrecognizedCharacter`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnrecognizedChar {
+    /// The character itself
+    pub character: char,
+
+    /// The byte offset of the character in the input
+    pub byte_offset: usize,
+
+    /// The index of the character in the input, counted in `char`s
+    pub char_index: usize,
+}
+
+impl UnrecognizedChar {
+    /// The Unicode general category of the character (e.g., `Ll` for a
+    /// lowercase Latin letter, or `Mn` for an unhandled diacritic)
+    #[must_use]
+    pub fn general_category(&self) -> GeneralCategory {
+        unicode_general_category::get_general_category(self.character)
+    }
+
+    /// The Unicode script of the character (`Common` for most punctuation and
+    /// digits, `Inherited` for most combining marks)
+    #[must_use]
+    pub fn script(&self) -> Script {
+        self.character.script()
+    }
+}
+
+impl fmt::Display for UnrecognizedChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {} (char {})",
+            self.character.escape_unicode(),
+            self.byte_offset,
+            self.char_index
+        )
+    }
 }
 
 /// We need to allow some options for _abjad_ calculation. At present there are
-/// four: three booleans and one `enum`. All of the booleans are false by default.
-/// The `enum` also has a default value, which should be suitable for the vast
+/// 14: 11 booleans and three `enum`s. All of the booleans are false by default.
+/// The `enum`s also have default values, which should be suitable for the vast
 /// majority of use cases. If you don't need to change any of the options, then,
 /// when calling one of the methods, you can simply pass `AbjadPrefs::default()`.
+///
+/// For common traditions, a `Preset` may be more convenient. There is also a
+/// builder, `AbjadPrefs::builder`, which checks the preferences for consistency.
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct AbjadPrefs {
     /// Count the [_shaddah_](https://en.wikipedia.org/wiki/Shadda) diacritic?
     /// This will have the effect of doubling the value of the preceding letter.
@@ -49,66 +254,281 @@ pub struct AbjadPrefs {
     pub ignore_lone_hamzah: bool,
 
     /// Which letter order to use: Mashriqi (default) or Maghribi? (Unless you
-    /// are certain that you need the latter, you probably don't.)
+    /// are certain that you need the latter, you probably don't.) A custom table
+    /// of values may also be given here.
     pub letter_order: LetterOrder,
+
+    /// Which set of letters to recognize beyond the basic Arabic alphabet: the
+    /// Persian additions (default), or those plus the Urdu or Ottoman letters?
+    pub orthography: Orthography,
+
+    /// Fold Arabic presentation forms (U+FB50–U+FDFF and U+FE70–U+FEFF, as often
+    /// found in text extracted from PDFs) to the letters they represent? Ligatures
+    /// are decomposed, so that ﻻ counts as لا and ﷲ as الله.
+    pub fold_presentation_forms: bool,
+
+    /// Ignore vowel signs and other diacritics (_harakat_, _tanwin_, _sukun_,
+    /// superscript _alif_, Qur'anic annotation marks in U+06D6–U+06ED, and the
+    /// like)? By default they are treated as unrecognized. (_Shaddah_ is always
+    /// accepted, and is governed by `count_shaddah`.)
+    pub ignore_diacritics: bool,
+
+    /// Count superscript (or "dagger") _alif_ (U+0670) as an _alif_, with value 1?
+    /// This applies whether or not other diacritics are ignored.
+    pub count_superscript_alif: bool,
+
+    /// Ignore whitespace other than the space (e.g., tabs, line breaks, and
+    /// non-breaking spaces)? Spaces and zero-width non-joiners are always ignored.
+    pub ignore_whitespace: bool,
+
+    /// Ignore the zero-width joiner and other invisible formatting characters
+    /// (the word joiner and the directional marks U+200E, U+200F, and U+061C)?
+    pub ignore_joiners: bool,
+
+    /// Ignore _kashida_ (_tatweel_, U+0640)?
+    pub ignore_kashida: bool,
+
+    /// Ignore punctuation (e.g., ، ؛ ؟ ۔ and their Latin counterparts)?
+    pub ignore_punctuation: bool,
+
+    /// Ignore digits, whether Western, Arabic-Indic (٠–٩), or Persian (۰–۹)?
+    pub ignore_digits: bool,
+
+    /// Which mode of calculation to use: the standard _abjad kabir_ (default), or
+    /// another, such as _abjad saghir_?
+    pub mode: CalculationMode,
 }
 
 /// This `enum` allows for a selection of the letter order for _abjad_ values
-/// (Mashriqi by default).
+/// (Mashriqi by default), or of a custom table of values.
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LetterOrder {
     /// Maghribi letter order
     Maghribi,
     #[default]
     /// Mashriqi letter order (default and much more common)
     Mashriqi,
+    /// A custom table of values
+    Custom(AbjadTable),
+}
+
+impl LetterOrder {
+    /// The table of values for this letter order
+    #[must_use]
+    pub const fn table(self) -> AbjadTable {
+        match self {
+            Self::Maghribi => AbjadTable::MAGHRIBI,
+            Self::Mashriqi => AbjadTable::MASHRIQI,
+            Self::Custom(table) => table,
+        }
+    }
+}
+
+/// This `enum` allows for a selection of the letters that are recognized, beyond
+/// the basic Arabic alphabet. Additional letters are valued as the letters from
+/// which they derive (e.g., Urdu ٹ as ت).
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Orthography {
+    #[default]
+    /// Arabic letters plus the Persian additions پ چ ژ گ ی ۀ (default)
+    Persian,
+    /// Persian letters plus the Urdu additions ٹ ڈ ڑ ں ہ ھ ۂ ۃ ے ۓ. The
+    /// retroflexes take the values of their base letters; ہ, ھ, ۂ, and ۃ are
+    /// counted as 5; ے and ۓ as 10; and ں as 50.
+    Urdu,
+    /// Persian letters plus the Ottoman Turkish additions ڭ ݣ ۋ. Following the
+    /// usual practice of Ottoman chronograms (_tarih düşürme_), _kâf-ı nûnî_
+    /// (_sağır kef_, written ڭ or ݣ) is counted as _kef_ (20), and ۋ as _vav_ (6).
+    /// As in Persian, _gef_ (گ) is counted as _kef_, and _pe_, _çim_, and _je_
+    /// take the values of _be_, _cim_, and _ze_.
+    Ottoman,
+}
+
+/// This `enum` allows for a selection of the mode of calculation. The standard
+/// values of the letters are those of _abjad kabir_ ("greater _abjad_"); other
+/// modes derive from them.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CalculationMode {
+    #[default]
+    /// _Abjad kabir_: letters have their standard values (default).
+    Kabir,
+    /// _Abjad saghir_ ("lesser _abjad_"): the value of each letter is reduced
+    /// modulo 12, as is done for astrological purposes, with a remainder of zero
+    /// counted as 12. Thus ا is 1, ي is 10, ك is 8, and س is 12. (To reduce a
+    /// total instead, take the _kabir_ value modulo 12.)
+    Saghir,
+    /// _Abjad ausat_ ("middle _abjad_"): the value of each letter is its ordinal
+    /// position in the _abjad_ sequence, from ا (1) to غ (28), or to ش (28) in
+    /// Maghribi order. (With a custom table, the sequence is that of its values.)
+    Ausat,
+    /// _Abjad malfuzi_ (or _akbar_): the value of each letter is that of its
+    /// name, spelled out, so that ا is الف (111), ب is با (3), and م is ميم (90).
+    /// The names are valued in the chosen letter order (or table). Letters
+    /// added in other languages take the name of the letter whose value they
+    /// share (e.g., پ that of ب), and the lone _hamzah_ that of _alif_.
+    Malfuzi(NameSpelling),
+}
+
+impl CalculationMode {
+    // Derive the value of a letter in this mode from its position in the alphabet
+    fn letter_value(self, index: usize, letter_order: LetterOrder) -> u32 {
+        let table = letter_order.table();
+        let kabir = table.value_at(index);
+
+        match self {
+            Self::Kabir => kabir,
+            Self::Saghir => match kabir % 12 {
+                0 => 12,
+                remainder => remainder,
+            },
+            Self::Ausat => table.ordinal_at(index),
+            Self::Malfuzi(spelling) => spelling.name_value(index, &table),
+        }
+    }
+}
+
+/// This `enum` records which rule was applied to a character in the course of
+/// an _abjad_ calculation. It is reported for each character by
+/// `abjad_breakdown`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LetterRule {
+    /// A letter valued according to the standard (Mashriqi) table, in the chosen
+    /// calculation mode
+    Letter,
+    /// A letter whose value is changed by the Maghribi letter order (again in the
+    /// chosen calculation mode)
+    Maghribi,
+    /// A letter whose value is changed by a custom table (again in the chosen
+    /// calculation mode)
+    Custom,
+    /// The _shaddah_ diacritic. Its value is that of the preceding letter if
+    /// `count_shaddah` is set; otherwise zero.
+    Shaddah,
+    /// _Alif maddah_. Its value is 2 if `double_alif_maddah` is set; otherwise 1.
+    AlifMaddah,
+    /// The lone _hamzah_. Its value is zero if `ignore_lone_hamzah` is set;
+    /// otherwise 1.
+    LoneHamzah,
+    /// A vowel sign or other diacritic, which is passed over with no value if
+    /// `ignore_diacritics` is set. Superscript _alif_ has value 1 if
+    /// `count_superscript_alif` is set.
+    Diacritic,
+    /// A combining _maddah_ or _hamzah_ (U+0653–U+0655), composed with the
+    /// preceding letter (e.g., ا with U+0653 as آ). Its value is whatever it adds
+    /// to that of the letter: 1 for a _maddah_ on _alif_ if `double_alif_maddah`
+    /// is set; otherwise 0.
+    CombiningMark,
+    /// A presentation form, folded to the letter or letters it represents. Its
+    /// value is the sum of theirs.
+    PresentationForm,
+    /// A space or zero-width non-joiner, or other whitespace or joiner if so
+    /// configured, which is passed over with no value
+    Separator,
+    /// _Kashida_, punctuation, or a digit, which is passed over with no value if
+    /// so configured
+    Ignored,
+    /// An unrecognized character, which is given no value
+    Unrecognized,
+}
+
+/// One entry in the trace returned by `abjad_breakdown`, describing how a single
+/// character contributed to the total.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BreakdownEntry {
+    /// The character itself
+    pub character: char,
+
+    /// The byte offset of the character in the input
+    pub byte_offset: usize,
+
+    /// The index of the character in the input, counted in `char`s
+    pub char_index: usize,
+
+    /// The value assigned to the character
+    pub value: u32,
+
+    /// The rule by which the value was assigned
+    pub rule: LetterRule,
+
+    /// The total of all values up to and including this character
+    pub running_total: u32,
 }
 
 /// This is the trait that we implement for `&str`, allowing us to use the new
-/// methods.
+/// methods. It is also implemented for `String`, `Cow<str>`, and `char`, and,
+/// through the `Chars` wrapper, for any iterator of `char`s.
 pub trait Abjad {
-    /// This returns a best-effort value, ignoring unrecognized characters.
+    /// This returns a best-effort value, ignoring unrecognized characters. The
+    /// total saturates at `u32::MAX`.
     fn abjad(self, prefs: AbjadPrefs) -> u32;
 
-    /// This returns a tuple, with unrecognized characters (Unicode-escaped)
-    /// in a `Vec`.
-    fn abjad_collect_errors(self, prefs: AbjadPrefs) -> (u32, Vec<String>);
+    /// This returns a tuple, with unrecognized characters (and their positions)
+    /// in a `Vec`. The total saturates at `u32::MAX`.
+    fn abjad_collect_errors(self, prefs: AbjadPrefs) -> (u32, Vec<UnrecognizedChar>);
 
     /// # Errors
-    /// This returns an error as soon as any unrecognized character is encountered.
+    /// This returns an error as soon as any unrecognized character is encountered,
+    /// or if the total would exceed `u32::MAX`.
     fn abjad_strict(self, prefs: AbjadPrefs) -> Result<u32, AbjadError>;
+
+    /// This returns a best-effort value, ignoring unrecognized characters, or
+    /// `None` if the total would exceed `u32::MAX`.
+    fn abjad_checked(self, prefs: AbjadPrefs) -> Option<u32>;
+
+    /// This returns a best-effort value, ignoring unrecognized characters, summed
+    /// into a `u64`. Use this for very long inputs.
+    fn abjad_wide(self, prefs: AbjadPrefs) -> u64;
+
+    /// This returns a trace of the calculation, with one entry per character.
+    /// Unrecognized characters are included, with a value of zero. The running
+    /// total saturates at `u32::MAX`.
+    fn abjad_breakdown(self, prefs: AbjadPrefs) -> Vec<BreakdownEntry>;
 }
 
-impl Abjad for &str {
+/// A wrapper allowing any iterator of `char`s (or anything else that can be
+/// turned into one) to be valued, e.g., `Chars(rope.chars()).abjad(prefs)`.
+/// Positions are reported as if the characters were a UTF-8 `str`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Chars<I>(pub I);
+
+impl<I: IntoIterator<Item = char>> Abjad for Chars<I> {
     fn abjad(self, prefs: AbjadPrefs) -> u32 {
         let mut abjad_total: u32 = 0;
-        let mut last_value: u32 = 0;
+        let mut valuer = LetterValuer::new(prefs);
 
-        for character in self.chars() {
-            if let Ok(new_value) = get_letter_value(character, last_value, prefs) {
-                abjad_total += new_value;
-                last_value = new_value;
-            } else {
-                last_value = 0;
-            }
+        for character in self.0 {
+            let (new_value, _) = valuer.value(character);
+
+            abjad_total = abjad_total.saturating_add(new_value);
         }
 
         abjad_total
     }
 
-    fn abjad_collect_errors(self, prefs: AbjadPrefs) -> (u32, Vec<String>) {
+    fn abjad_collect_errors(self, prefs: AbjadPrefs) -> (u32, Vec<UnrecognizedChar>) {
         let mut abjad_total: u32 = 0;
-        let mut errors: Vec<String> = Vec::new();
-        let mut last_value: u32 = 0;
+        let mut errors: Vec<UnrecognizedChar> = Vec::new();
+        let mut valuer = LetterValuer::new(prefs);
 
-        for character in self.chars() {
-            if let Ok(new_value) = get_letter_value(character, last_value, prefs) {
-                abjad_total += new_value;
-                last_value = new_value;
-            } else {
-                errors.push(character.escape_unicode().collect());
-                last_value = 0;
+        for (byte_offset, char_index, character) in char_positions(self.0) {
+            let (new_value, rule) = valuer.value(character);
+
+            if rule == LetterRule::Unrecognized {
+                errors.push(UnrecognizedChar {
+                    character,
+                    byte_offset,
+                    char_index,
+                });
             }
+
+            abjad_total = abjad_total.saturating_add(new_value);
         }
 
         (abjad_total, errors)
@@ -116,119 +536,378 @@ impl Abjad for &str {
 
     fn abjad_strict(self, prefs: AbjadPrefs) -> Result<u32, AbjadError> {
         let mut abjad_total: u32 = 0;
-        let mut last_value: u32 = 0;
+        let mut valuer = LetterValuer::new(prefs);
 
-        for character in self.chars() {
-            let new_value = get_letter_value(character, last_value, prefs)?;
+        for (byte_offset, char_index, character) in char_positions(self.0) {
+            let (new_value, rule) = valuer.value(character);
 
-            abjad_total += new_value;
-            last_value = new_value;
+            if rule == LetterRule::Unrecognized {
+                return Err(AbjadError::UnrecognizedCharacter(UnrecognizedChar {
+                    character,
+                    byte_offset,
+                    char_index,
+                }));
+            }
+
+            abjad_total = abjad_total
+                .checked_add(new_value)
+                .ok_or(AbjadError::Overflow {
+                    byte_offset,
+                    char_index,
+                })?;
         }
 
         Ok(abjad_total)
     }
+
+    fn abjad_checked(self, prefs: AbjadPrefs) -> Option<u32> {
+        let mut abjad_total: u32 = 0;
+        let mut valuer = LetterValuer::new(prefs);
+
+        for character in self.0 {
+            let (new_value, _) = valuer.value(character);
+
+            abjad_total = abjad_total.checked_add(new_value)?;
+        }
+
+        Some(abjad_total)
+    }
+
+    fn abjad_wide(self, prefs: AbjadPrefs) -> u64 {
+        let mut abjad_total: u64 = 0;
+        let mut valuer = LetterValuer::new(prefs);
+
+        for character in self.0 {
+            let (new_value, _) = valuer.value(character);
+
+            abjad_total += u64::from(new_value);
+        }
+
+        abjad_total
+    }
+
+    fn abjad_breakdown(self, prefs: AbjadPrefs) -> Vec<BreakdownEntry> {
+        let mut entries: Vec<BreakdownEntry> = Vec::new();
+        let mut abjad_total: u32 = 0;
+        let mut valuer = LetterValuer::new(prefs);
+
+        for (byte_offset, char_index, character) in char_positions(self.0) {
+            let (new_value, rule) = valuer.value(character);
+
+            abjad_total = abjad_total.saturating_add(new_value);
+
+            entries.push(BreakdownEntry {
+                character,
+                byte_offset,
+                char_index,
+                value: new_value,
+                rule,
+                running_total: abjad_total,
+            });
+        }
+
+        entries
+    }
 }
 
-fn get_letter_value(
-    character: char,
-    last_value: u32,
-    prefs: AbjadPrefs,
-) -> Result<u32, AbjadError> {
-    let maghribi_order = prefs.letter_order == LetterOrder::Maghribi;
+// Each character with its byte offset (as if encoded in UTF-8) and char index
+fn char_positions(
+    chars: impl IntoIterator<Item = char>,
+) -> impl Iterator<Item = (usize, usize, char)> {
+    chars
+        .into_iter()
+        .enumerate()
+        .scan(0, |byte_offset, (char_index, character)| {
+            let position = (*byte_offset, char_index, character);
+            *byte_offset += character.len_utf8();
+            Some(position)
+        })
+}
 
-    let mut letter_value: u32 = 0;
+// Text types are valued as iterators of chars
+macro_rules! impl_abjad_via_chars {
+    ($($(#[$doc:meta])* $type:ty => |$self:ident| $chars:expr;)*) => {$(
+        $(#[$doc])*
+        impl Abjad for $type {
+            fn abjad(self, prefs: AbjadPrefs) -> u32 {
+                let $self = self;
+                Chars($chars).abjad(prefs)
+            }
 
-    match character {
-        'ا' | 'أ' | 'إ' | 'ٱ' => letter_value = 1,
-        'آ' => {
-            if prefs.double_alif_maddah {
-                letter_value = 2;
-            } else {
-                letter_value = 1;
+            fn abjad_collect_errors(self, prefs: AbjadPrefs) -> (u32, Vec<UnrecognizedChar>) {
+                let $self = self;
+                Chars($chars).abjad_collect_errors(prefs)
             }
-        }
-        'ء' => {
-            if !prefs.ignore_lone_hamzah {
-                letter_value = 1;
+
+            fn abjad_strict(self, prefs: AbjadPrefs) -> Result<u32, AbjadError> {
+                let $self = self;
+                Chars($chars).abjad_strict(prefs)
             }
-        }
-        'ب' | 'پ' => letter_value = 2,
-        'ج' | 'چ' => letter_value = 3,
-        'د' => letter_value = 4,
-        'ه' | 'ة' | 'ۀ' => letter_value = 5,
-        'و' | 'ؤ' => letter_value = 6,
-        'ز' | 'ژ' => letter_value = 7,
-        'ح' => letter_value = 8,
-        'ط' => letter_value = 9,
-        'ي' | 'ى' | 'ئ' | 'ی' => letter_value = 10,
-        'ك' | 'ک' | 'گ' => letter_value = 20,
-        'ل' => letter_value = 30,
-        'م' => letter_value = 40,
-        'ن' => letter_value = 50,
-        'س' => {
-            if maghribi_order {
-                letter_value = 300;
-            } else {
-                letter_value = 60;
+
+            fn abjad_checked(self, prefs: AbjadPrefs) -> Option<u32> {
+                let $self = self;
+                Chars($chars).abjad_checked(prefs)
             }
-        }
-        'ع' => letter_value = 70,
-        'ف' => letter_value = 80,
-        'ص' => {
-            if maghribi_order {
-                letter_value = 60;
-            } else {
-                letter_value = 90;
+
+            fn abjad_wide(self, prefs: AbjadPrefs) -> u64 {
+                let $self = self;
+                Chars($chars).abjad_wide(prefs)
             }
-        }
-        'ق' => letter_value = 100,
-        'ر' => letter_value = 200,
-        'ش' => {
-            if maghribi_order {
-                letter_value = 1000;
-            } else {
-                letter_value = 300;
+
+            fn abjad_breakdown(self, prefs: AbjadPrefs) -> Vec<BreakdownEntry> {
+                let $self = self;
+                Chars($chars).abjad_breakdown(prefs)
             }
         }
-        'ت' => letter_value = 400,
-        'ث' => letter_value = 500,
-        'خ' => letter_value = 600,
-        'ذ' => letter_value = 700,
-        'ض' => {
-            if maghribi_order {
-                letter_value = 90;
-            } else {
-                letter_value = 800;
+    )*};
+}
+
+impl_abjad_via_chars! {
+    &str => |text| text.chars();
+    String => |text| text.chars();
+    &String => |text| text.chars();
+    Cow<'_, str> => |text| text.chars();
+    &Cow<'_, str> => |text| text.chars();
+    /// A single `char` is valued on its own, so that, e.g., a _shaddah_ has no
+    /// value, and `abjad_strict` returns an error if it is not recognized.
+    char => |character| std::iter::once(character);
+    /// Transliterated text is valued in Arabic script.
+    &Transliteration => |transliteration| transliteration.arabic.chars();
+}
+
+// This keeps track of the value of the last letter, which is needed for shaddah
+#[derive(Clone, Copy, Debug)]
+struct LetterValuer {
+    prefs: AbjadPrefs,
+    last_value: u32,
+    // The last letter (and its value), which may take a combining mark
+    base: Option<(char, u32)>,
+    // Whether a shaddah has counted the base letter a second time
+    base_doubled: bool,
+}
+
+impl LetterValuer {
+    const fn new(prefs: AbjadPrefs) -> Self {
+        Self {
+            prefs,
+            last_value: 0,
+            base: None,
+            base_doubled: false,
+        }
+    }
+
+    fn value(&mut self, character: char) -> (u32, LetterRule) {
+        if self.prefs.fold_presentation_forms && is_presentation_form(character) {
+            self.base = None;
+            return self.value_presentation_form(character);
+        }
+
+        if let Some(value) = self.value_combining_mark(character) {
+            return value;
+        }
+
+        let (letter_value, rule) = get_letter_value(character, self.last_value, self.prefs);
+
+        // Diacritics may come between a letter and its shaddah
+        if rule != LetterRule::Diacritic {
+            self.last_value = letter_value;
+        }
+
+        // Or between a letter and a combining mark
+        if !is_diacritic(character) {
+            self.base = Some((character, letter_value));
+            self.base_doubled = false;
+        } else if rule == LetterRule::Shaddah && letter_value > 0 {
+            self.base_doubled = true;
+        }
+
+        (letter_value, rule)
+    }
+
+    // A combining maddah or hamzah is composed with the letter before it, as in
+    // NFC, and given whatever value it adds to that letter. In NFD, a shaddah
+    // comes before the mark, so if it has already doubled the letter, the mark
+    // adds its increase twice.
+    fn value_combining_mark(&mut self, character: char) -> Option<(u32, LetterRule)> {
+        let (base, base_value) = self.base?;
+        let composed = compose(base, character)?;
+
+        let (composed_value, rule) = get_letter_value(composed, self.last_value, self.prefs);
+
+        if rule == LetterRule::Unrecognized {
+            return None;
+        }
+
+        self.base = Some((composed, composed_value));
+        self.last_value = composed_value;
+
+        let increase = composed_value.saturating_sub(base_value);
+        let times = if self.base_doubled { 2 } else { 1 };
+
+        Some((increase.saturating_mul(times), LetterRule::CombiningMark))
+    }
+
+    fn value_presentation_form(&mut self, character: char) -> (u32, LetterRule) {
+        let mut form_value: u32 = 0;
+
+        // NFKC maps presentation forms to the letters they represent
+        for folded in std::iter::once(character).nfkc() {
+            let (letter_value, rule) = get_letter_value(folded, self.last_value, self.prefs);
+
+            if rule == LetterRule::Unrecognized {
+                self.last_value = 0;
+                return (0, LetterRule::Unrecognized);
+            }
+
+            form_value = form_value.saturating_add(letter_value);
+
+            if rule != LetterRule::Diacritic {
+                self.last_value = letter_value;
             }
         }
-        'ظ' => {
-            if maghribi_order {
-                letter_value = 800;
+
+        (form_value, LetterRule::PresentationForm)
+    }
+}
+
+// The canonical compositions of Arabic letters with U+0653–U+0655, plus ه and
+// the other forms of ي with hamzah above, as typed on Persian keyboards
+const fn compose(base: char, mark: char) -> Option<char> {
+    let composed = match (base, mark) {
+        ('ا', '\u{0653}') => 'آ',
+        ('ا', '\u{0654}') => 'أ',
+        ('ا', '\u{0655}') => 'إ',
+        ('و', '\u{0654}') => 'ؤ',
+        ('ي' | 'ی' | 'ى', '\u{0654}') => 'ئ',
+        ('ه' | 'ە', '\u{0654}') => 'ۀ',
+        ('ہ', '\u{0654}') => 'ۂ',
+        ('ے', '\u{0654}') => 'ۓ',
+        _ => return None,
+    };
+
+    Some(composed)
+}
+
+const fn is_presentation_form(character: char) -> bool {
+    matches!(character, '\u{FB50}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}')
+}
+
+fn get_letter_value(character: char, last_value: u32, prefs: AbjadPrefs) -> (u32, LetterRule) {
+    let mut letter_value: u32 = 0;
+    let mut rule = LetterRule::Letter;
+
+    match fold_letter(character, prefs.orthography) {
+        'آ' => {
+            rule = LetterRule::AlifMaddah;
+
+            let alif = prefs.mode.letter_value(0, prefs.letter_order);
+
+            if prefs.double_alif_maddah {
+                letter_value = alif.saturating_mul(2);
             } else {
-                letter_value = 900;
+                letter_value = alif;
             }
         }
-        'غ' => {
-            if maghribi_order {
-                letter_value = 900;
-            } else {
-                letter_value = 1000;
+        'ء' => {
+            rule = LetterRule::LoneHamzah;
+
+            if !prefs.ignore_lone_hamzah {
+                letter_value = prefs.mode.letter_value(0, prefs.letter_order);
             }
         }
         // Shaddah diacritic
         '\u{0651}' => {
+            rule = LetterRule::Shaddah;
+
             if prefs.count_shaddah {
                 letter_value = last_value;
             }
         }
-        // Space or zwnj is ok
-        ' ' | '\u{200C}' => {}
-        // Otherwise return error
-        _ => {
-            let escaped: String = character.escape_unicode().collect();
-            return Err(AbjadError::UnrecognizedCharacter(escaped));
+        // Superscript alif
+        '\u{0670}' if prefs.count_superscript_alif => {
+            letter_value = prefs.mode.letter_value(0, prefs.letter_order);
+            rule = LetterRule::Diacritic;
         }
+        // Other diacritics are ok if so configured
+        c if prefs.ignore_diacritics && is_diacritic(c) => rule = LetterRule::Diacritic,
+        // Space or zwnj is ok
+        ' ' | '\u{200C}' => rule = LetterRule::Separator,
+        // Other whitespace and joiners are ok if so configured
+        c if prefs.ignore_whitespace && c.is_whitespace() => rule = LetterRule::Separator,
+        c if prefs.ignore_joiners && is_joiner(c) => rule = LetterRule::Separator,
+        // As are kashida, punctuation, and digits
+        '\u{0640}' if prefs.ignore_kashida => rule = LetterRule::Ignored,
+        c if prefs.ignore_punctuation && is_punctuation(c) => rule = LetterRule::Ignored,
+        c if prefs.ignore_digits && is_digit(c) => rule = LetterRule::Ignored,
+        // Otherwise look up the letter
+        c => match letter_index(c) {
+            Some(index) => {
+                letter_value = prefs.mode.letter_value(index, prefs.letter_order);
+
+                let table = prefs.letter_order.table();
+
+                if table.value_at(index) != AbjadTable::MASHRIQI.value_at(index) {
+                    rule = match prefs.letter_order {
+                        LetterOrder::Custom(_) => LetterRule::Custom,
+                        _ => LetterRule::Maghribi,
+                    };
+                }
+            }
+            None => rule = LetterRule::Unrecognized,
+        },
+    }
+
+    (letter_value, rule)
+}
+
+// Map letters specific to an orthography onto the letters from which they derive
+const fn fold_letter(character: char, orthography: Orthography) -> char {
+    match (orthography, character) {
+        (Orthography::Urdu, 'ٹ') => 'ت',
+        (Orthography::Urdu, 'ڈ') => 'د',
+        (Orthography::Urdu, 'ڑ') => 'ر',
+        (Orthography::Urdu, 'ں') => 'ن',
+        (Orthography::Urdu, 'ہ' | 'ھ' | 'ۂ') => 'ه',
+        (Orthography::Urdu, 'ۃ') => 'ة',
+        (Orthography::Urdu, 'ے' | 'ۓ') => 'ي',
+        (Orthography::Ottoman, 'ڭ' | 'ݣ') => 'ك',
+        (Orthography::Ottoman, 'ۋ') => 'و',
+        _ => character,
     }
+}
+
+// Harakat, tanwin, sukun, and the like, as well as Qur'anic annotation marks
+const fn is_diacritic(character: char) -> bool {
+    matches!(
+        character,
+        '\u{0610}'..='\u{061A}'
+            | '\u{064B}'..='\u{065F}'
+            | '\u{0670}'
+            | '\u{06D6}'..='\u{06ED}'
+    )
+}
+
+// Zero-width joiner, word joiner, and directional marks
+const fn is_joiner(character: char) -> bool {
+    matches!(
+        character,
+        '\u{200D}' | '\u{2060}' | '\u{200E}' | '\u{200F}' | '\u{061C}'
+    )
+}
+
+fn is_punctuation(character: char) -> bool {
+    matches!(
+        unicode_general_category::get_general_category(character),
+        GeneralCategory::ConnectorPunctuation
+            | GeneralCategory::DashPunctuation
+            | GeneralCategory::OpenPunctuation
+            | GeneralCategory::ClosePunctuation
+            | GeneralCategory::InitialPunctuation
+            | GeneralCategory::FinalPunctuation
+            | GeneralCategory::OtherPunctuation
+    )
+}
 
-    Ok(letter_value)
+fn is_digit(character: char) -> bool {
+    unicode_general_category::get_general_category(character) == GeneralCategory::DecimalNumber
 }
@@ -0,0 +1,510 @@
+//! The `abjad` command-line tool, built with the `cli` feature. Run with `--help`
+//! for usage.
+
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use std::fs;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use abjad::{
+    Abjad, AbjadError, AbjadPrefs, AbjadPrefsBuilder, AbjadTable, AbjadWords, BreakdownEntry,
+    LetterOrder, Preset, Romanization, UnrecognizedChar, Word,
+};
+use serde_json::{json, Value};
+
+const USAGE: &str = "\
+Usage: abjad [OPTIONS] [TEXT]...
+
+Prints the abjad value of TEXT (the arguments joined by spaces), or of standard
+input if no TEXT is given.
+
+Checking:
+      --lenient                  Ignore unrecognized characters (default)
+      --collect                  Report unrecognized characters, and exit with 1
+                                 if there are any
+      --strict                   Stop at the first unrecognized character, and
+                                 exit with 1
+
+Output:
+  -w, --words                    Print the value of each word
+  -l, --letters                  Print the value of each character
+  -f, --format <FORMAT>          text (default), json, or csv
+  -r, --romanization <SCHEME>    Label each character with its name and symbol
+                                 in ijmes (default), ala-lc, or dmg
+
+Preferences:
+  -p, --preset <PRESET>          Start from a preset (given once): classical-arabic,
+                                 maghribi, ottoman-tarih, persian-chronogram, or
+                                 quranic
+      --letter-order <ORDER>     mashriqi (default) or maghribi
+      --table <FILE>             Use a custom table of values, read from FILE
+      --orthography <NAME>       persian (default), urdu, or ottoman
+      --mode <MODE>              kabir (default), saghir, ausat, malfuzi, or
+                                 malfuzi-hamzated
+      --count-shaddah
+      --double-alif-maddah
+      --ignore-lone-hamzah
+      --fold-presentation-forms
+      --ignore-diacritics
+      --count-superscript-alif
+      --ignore-whitespace
+      --ignore-joiners
+      --ignore-kashida
+      --ignore-punctuation
+      --ignore-digits
+                                 Each of these sets the preference of the same
+                                 name; prefix with no- (e.g., --no-ignore-digits)
+                                 to unset it
+
+  -h, --help                     Print this help
+  -V, --version                  Print the version
+
+Exit status: 0 on success; 1 if unrecognized characters are found (with
+--collect or --strict); 2 for invalid arguments (including a table that cannot
+be read); 3 for other errors, such as overflow (with --strict).";
+
+// Options that take a value
+const VALUE_OPTIONS: [&str; 10] = [
+    "-p",
+    "--preset",
+    "-f",
+    "--format",
+    "-r",
+    "--romanization",
+    "--letter-order",
+    "--table",
+    "--orthography",
+    "--mode",
+];
+
+// Exit statuses
+const UNRECOGNIZED: u8 = 1;
+const USAGE_ERROR: u8 = 2;
+const FAILURE: u8 = 3;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Check {
+    #[default]
+    Lenient,
+    Collect,
+    Strict,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    prefs: AbjadPrefs,
+    check: Check,
+    format: Format,
+    words: bool,
+    letters: bool,
+    romanization: Romanization,
+    text: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let options = match parse_args(&args) {
+        Ok(Some(options)) => options,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("abjad: {message}\nTry abjad --help for more information.");
+            return ExitCode::from(USAGE_ERROR);
+        }
+    };
+
+    let input = if options.text.is_empty() {
+        let mut input = String::new();
+
+        if let Err(error) = io::stdin().read_to_string(&mut input) {
+            eprintln!("abjad: {error}");
+            return ExitCode::from(FAILURE);
+        }
+
+        // A final line break is not part of the text
+        input.truncate(input.trim_end_matches(['\n', '\r']).len());
+        input
+    } else {
+        options.text.join(" ")
+    };
+
+    run(&options, &input)
+}
+
+// Returns None if help or the version was printed
+fn parse_args(args: &[String]) -> Result<Option<Options>, String> {
+    let mut options = Options::default();
+
+    // A preset is applied first, so that other options can modify it
+    if let Some(preset) = find_preset(args)? {
+        options.prefs = preset.prefs();
+    }
+
+    let mut index = 0;
+
+    while index < args.len() {
+        let arg = args[index].as_str();
+
+        match arg {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(None);
+            }
+            "-V" | "--version" => {
+                println!("abjad {}", env!("CARGO_PKG_VERSION"));
+                return Ok(None);
+            }
+            "--lenient" => options.check = Check::Lenient,
+            "--collect" => options.check = Check::Collect,
+            "--strict" => options.check = Check::Strict,
+            "-w" | "--words" => options.words = true,
+            "-l" | "--letters" => options.letters = true,
+            "--" => {
+                options.text.extend(args[index + 1..].iter().cloned());
+                break;
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                index = parse_option(&mut options, args, index)?;
+            }
+            _ => options.text.push(arg.to_string()),
+        }
+
+        index += 1;
+    }
+
+    if options.format == Format::Csv && options.words && options.letters {
+        return Err("--words and --letters cannot be combined in CSV".to_string());
+    }
+
+    options.prefs = AbjadPrefsBuilder::from(options.prefs)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    Ok(Some(options))
+}
+
+// Handles an option other than a simple switch, returning the index of its last
+// argument
+fn parse_option(options: &mut Options, args: &[String], index: usize) -> Result<usize, String> {
+    let arg = args[index].as_str();
+
+    if let Some(name) = arg.strip_prefix("--no-") {
+        return if set_flag(&mut options.prefs, name, false) {
+            Ok(index)
+        } else {
+            Err(format!("unknown option: {arg}"))
+        };
+    }
+
+    if let Some(name) = arg.strip_prefix("--") {
+        if set_flag(&mut options.prefs, name, true) {
+            return Ok(index);
+        }
+    }
+
+    let name = arg.split_once('=').map_or(arg, |(name, _)| name);
+
+    if !VALUE_OPTIONS.contains(&name) {
+        return Err(format!("unknown option: {arg}"));
+    }
+
+    let (value, last) = option_value(args, index)?;
+
+    match name {
+        // Already applied by find_preset
+        "-p" | "--preset" => {
+            value.parse::<Preset>().map_err(|e| e.to_string())?;
+        }
+        "-f" | "--format" => {
+            options.format = match value.to_ascii_lowercase().as_str() {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                "csv" => Format::Csv,
+                _ => return Err(format!("unknown format: {value}")),
+            };
+        }
+        "-r" | "--romanization" => {
+            options.romanization = value.parse().map_err(|e: AbjadError| e.to_string())?;
+        }
+        "--letter-order" => {
+            options.prefs.letter_order = value.parse().map_err(|e: AbjadError| e.to_string())?;
+        }
+        "--table" => {
+            let table = fs::read_to_string(value).map_err(|e| format!("{value}: {e}"))?;
+            let table: AbjadTable = table.parse().map_err(|e: AbjadError| e.to_string())?;
+            options.prefs.letter_order = LetterOrder::Custom(table);
+        }
+        "--orthography" => {
+            options.prefs.orthography = value.parse().map_err(|e: AbjadError| e.to_string())?;
+        }
+        "--mode" => {
+            options.prefs.mode = value.parse().map_err(|e: AbjadError| e.to_string())?;
+        }
+        _ => return Err(format!("unknown option: {arg}")),
+    }
+
+    Ok(last)
+}
+
+// The value of an option, given either as --option=value or as the next
+// argument, and the index of the last argument used
+// Finds the preset, if any, among the options (before --), skipping the values
+// of other options
+fn find_preset(args: &[String]) -> Result<Option<Preset>, String> {
+    let mut preset: Option<Preset> = None;
+    let mut index = 0;
+
+    while index < args.len() && args[index] != "--" {
+        let arg = args[index].as_str();
+        let name = arg.split_once('=').map_or(arg, |(name, _)| name);
+
+        if VALUE_OPTIONS.contains(&name) {
+            let (value, last) = option_value(args, index)?;
+
+            if is_option(arg, "-p", "--preset") {
+                if preset.is_some() {
+                    return Err("--preset cannot be given more than once".to_string());
+                }
+
+                preset = Some(value.parse().map_err(|e: AbjadError| e.to_string())?);
+            }
+
+            index = last;
+        }
+
+        index += 1;
+    }
+
+    Ok(preset)
+}
+
+fn option_value(args: &[String], index: usize) -> Result<(&str, usize), String> {
+    let arg = args[index].as_str();
+
+    if let Some((_, value)) = arg.split_once('=') {
+        return Ok((value, index));
+    }
+
+    args.get(index + 1)
+        .map(|value| (value.as_str(), index + 1))
+        .ok_or_else(|| format!("{arg} requires a value"))
+}
+
+fn is_option(arg: &str, short: &str, long: &str) -> bool {
+    let name = arg.split_once('=').map_or(arg, |(name, _)| name);
+    name == short || name == long
+}
+
+// Sets a boolean preference by name, returning false if there is none such
+fn set_flag(prefs: &mut AbjadPrefs, name: &str, on: bool) -> bool {
+    let field = match name {
+        "count-shaddah" => &mut prefs.count_shaddah,
+        "double-alif-maddah" => &mut prefs.double_alif_maddah,
+        "ignore-lone-hamzah" => &mut prefs.ignore_lone_hamzah,
+        "fold-presentation-forms" => &mut prefs.fold_presentation_forms,
+        "ignore-diacritics" => &mut prefs.ignore_diacritics,
+        "count-superscript-alif" => &mut prefs.count_superscript_alif,
+        "ignore-whitespace" => &mut prefs.ignore_whitespace,
+        "ignore-joiners" => &mut prefs.ignore_joiners,
+        "ignore-kashida" => &mut prefs.ignore_kashida,
+        "ignore-punctuation" => &mut prefs.ignore_punctuation,
+        "ignore-digits" => &mut prefs.ignore_digits,
+        _ => return false,
+    };
+
+    *field = on;
+    true
+}
+
+fn run(options: &Options, input: &str) -> ExitCode {
+    let prefs = options.prefs;
+
+    let (total, errors) = match options.check {
+        Check::Lenient => (input.abjad(prefs), Vec::new()),
+        Check::Collect => input.abjad_collect_errors(prefs),
+        Check::Strict => match input.abjad_strict(prefs) {
+            Ok(total) => (total, Vec::new()),
+            Err(error) => return report_error(&error, options.format),
+        },
+    };
+
+    let words: Vec<Word> = if options.words {
+        AbjadWords::new(input, prefs).collect()
+    } else {
+        Vec::new()
+    };
+
+    let letters = if options.letters {
+        input.abjad_breakdown(prefs)
+    } else {
+        Vec::new()
+    };
+
+    match options.format {
+        Format::Text => print_text(options, total, &words, &letters),
+        Format::Json => print_json(options, total, &errors, &words, &letters),
+        Format::Csv => print_csv(options, total, &words, &letters),
+    }
+
+    for error in &errors {
+        eprintln!("abjad: unrecognized character: {error}");
+    }
+
+    if errors.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(UNRECOGNIZED)
+    }
+}
+
+fn report_error(error: &AbjadError, format: Format) -> ExitCode {
+    if format == Format::Json {
+        println!("{}", json!({ "error": error }));
+    } else {
+        eprintln!("abjad: {error}");
+    }
+
+    match error {
+        AbjadError::UnrecognizedCharacter(_) => ExitCode::from(UNRECOGNIZED),
+        _ => ExitCode::from(FAILURE),
+    }
+}
+
+fn print_text(options: &Options, total: u32, words: &[Word], letters: &[BreakdownEntry]) {
+    for word in words {
+        println!("{}\t{}", word.text, word.value);
+    }
+
+    for entry in letters {
+        let (symbol, name) = label(entry, options.romanization);
+
+        println!(
+            "{}\t{}\t{}\t{}\t{symbol}\t{name}",
+            display_char(entry.character),
+            entry.value,
+            rule_name(entry),
+            entry.running_total
+        );
+    }
+
+    if options.words || options.letters {
+        println!("\ntotal\t{total}");
+    } else {
+        println!("{total}");
+    }
+}
+
+fn print_json(
+    options: &Options,
+    total: u32,
+    errors: &[UnrecognizedChar],
+    words: &[Word],
+    letters: &[BreakdownEntry],
+) {
+    let mut output = json!({ "total": total });
+
+    if options.check == Check::Collect {
+        output["errors"] = json!(errors);
+    }
+
+    if options.words {
+        output["words"] = json!(words);
+    }
+
+    if options.letters {
+        let labeled: Vec<Value> = letters
+            .iter()
+            .map(|entry| {
+                let mut value = json!(entry);
+                value["label"] = json!(entry.label(options.romanization));
+                value
+            })
+            .collect();
+
+        output["letters"] = json!(labeled);
+    }
+
+    println!("{output}");
+}
+
+fn print_csv(options: &Options, total: u32, words: &[Word], letters: &[BreakdownEntry]) {
+    if !words.is_empty() {
+        println!("text,start,end,value,errors");
+
+        for word in words {
+            println!(
+                "{},{},{},{},{}",
+                csv_field(word.text),
+                word.start,
+                word.end,
+                word.value,
+                word.errors.len()
+            );
+        }
+    } else if !letters.is_empty() {
+        println!("character,byte_offset,char_index,value,rule,running_total,symbol,name");
+
+        for entry in letters {
+            let (symbol, name) = label(entry, options.romanization);
+
+            println!(
+                "{},{},{},{},{},{},{},{}",
+                csv_field(&entry.character.to_string()),
+                entry.byte_offset,
+                entry.char_index,
+                entry.value,
+                rule_name(entry),
+                entry.running_total,
+                csv_field(symbol),
+                csv_field(name)
+            );
+        }
+    } else {
+        println!("total\n{total}");
+    }
+}
+
+// The symbol and name of a letter, or empty strings for anything else
+fn label(entry: &BreakdownEntry, romanization: Romanization) -> (&'static str, &'static str) {
+    entry
+        .label(romanization)
+        .map_or(("", ""), |label| (label.symbol, label.name))
+}
+
+// The rule as named in JSON output
+fn rule_name(entry: &BreakdownEntry) -> String {
+    match json!(entry.rule) {
+        Value::String(name) => name,
+        other => other.to_string(),
+    }
+}
+
+// Invisible characters are shown as escapes
+fn display_char(character: char) -> String {
+    if character.is_whitespace()
+        || character.is_control()
+        || ('\u{200B}'..='\u{200F}').contains(&character)
+    {
+        character.escape_unicode().to_string()
+    } else {
+        character.to_string()
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
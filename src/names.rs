@@ -0,0 +1,76 @@
+use crate::table::{AbjadTable, ABJAD_LETTERS};
+
+/// This `enum` allows for a selection of the spelling of letter names in _abjad
+/// malfuzi_. The spellings differ only for letters whose names end in a long
+/// vowel: ب, ح, ر, ز, ط, ظ, ف, ت, ث, خ, ه, and ي.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NameSpelling {
+    /// Names ending in _hamzah_: باء, راء, زاء, and so on
+    Hamzated,
+    #[default]
+    /// Names without _hamzah_: با, را, زاي, and so on (default)
+    Plain,
+}
+
+impl NameSpelling {
+    /// The spelled-out name of a letter, or `None` if the character is not one of
+    /// the 28 letters of the alphabet.
+    #[must_use]
+    pub fn name(self, letter: char) -> Option<&'static str> {
+        let index = ABJAD_LETTERS.iter().position(|&l| l == letter)?;
+        let (plain, hamzated) = LETTER_NAMES[index];
+
+        match self {
+            Self::Plain => Some(plain),
+            Self::Hamzated => Some(hamzated),
+        }
+    }
+
+    // The value of the name of the letter at the given position in the alphabet
+    pub(crate) fn name_value(self, index: usize, table: &AbjadTable) -> u32 {
+        let (plain, hamzated) = LETTER_NAMES[index];
+        let name = match self {
+            Self::Plain => plain,
+            Self::Hamzated => hamzated,
+        };
+
+        name.chars()
+            // Hamzah is valued as alif
+            .map(|c| table.value(c).unwrap_or_else(|| table.value_at(0)))
+            .fold(0, u32::saturating_add)
+    }
+}
+
+// Names of the letters, in Mashriqi order, in plain and hamzated spellings
+const LETTER_NAMES: [(&str, &str); 28] = [
+    ("الف", "الف"),
+    ("با", "باء"),
+    ("جيم", "جيم"),
+    ("دال", "دال"),
+    ("ها", "هاء"),
+    ("واو", "واو"),
+    ("زاي", "زاء"),
+    ("حا", "حاء"),
+    ("طا", "طاء"),
+    ("يا", "ياء"),
+    ("كاف", "كاف"),
+    ("لام", "لام"),
+    ("ميم", "ميم"),
+    ("نون", "نون"),
+    ("سين", "سين"),
+    ("عين", "عين"),
+    ("فا", "فاء"),
+    ("صاد", "صاد"),
+    ("قاف", "قاف"),
+    ("را", "راء"),
+    ("شين", "شين"),
+    ("تا", "تاء"),
+    ("ثا", "ثاء"),
+    ("خا", "خاء"),
+    ("ذال", "ذال"),
+    ("ضاد", "ضاد"),
+    ("ظا", "ظاء"),
+    ("غين", "غين"),
+];
@@ -0,0 +1,313 @@
+use std::fmt;
+
+use crate::table::ABJAD_LETTERS;
+use crate::{AbjadError, LetterOrder};
+
+/// Options for writing numbers in _abjad_ notation. As with `AbjadPrefs`, the
+/// defaults should suit most purposes; in that case, pass `NumeralPrefs::default()`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NumeralPrefs {
+    /// Which letter order to use: Mashriqi (default) or Maghribi? In the latter,
+    /// the letter for 1,000 is ش rather than غ. A custom table may also be used,
+    /// if it has letters for the values needed.
+    pub letter_order: LetterOrder,
+
+    /// How to write multiples of 1,000: with a multiplier (default), or by
+    /// repeating the letter for 1,000?
+    pub thousands: ThousandsStyle,
+
+    /// In which order to write the components of a number: from largest to
+    /// smallest (default), or from smallest to largest?
+    pub component_order: ComponentOrder,
+}
+
+/// This `enum` allows for a selection of the convention for writing multiples
+/// of 1,000.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ThousandsStyle {
+    #[default]
+    /// The number of thousands is written before the letter for 1,000 (or after
+    /// it, in ascending order), as a multiplier: 3,000 is جغ (default). Larger
+    /// multipliers are written in full, so that 12,000 is يبغ, and 1,000,000 is
+    /// غغ.
+    Multiplier,
+    /// The letter for 1,000 is repeated: 3,000 is غغغ.
+    Repeated,
+}
+
+/// This `enum` allows for a selection of the order of the components (thousands,
+/// hundreds, tens, and units) of a number in _abjad_ notation.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ComponentOrder {
+    /// From smallest to largest: 1,295 is هصرغ. The numeral is written as in
+    /// descending order, but reversed, so that a multiplier of thousands follows
+    /// the letter for 1,000 (in ascending order): 2,500 is ثغب, and 12,000 is غبي.
+    Ascending,
+    #[default]
+    /// From largest to smallest: 1,295 is غرصه (default)
+    Descending,
+}
+
+/// This `enum` describes what is wrong with a malformed _abjad_ numeral. It is
+/// reported by `AbjadError::MalformedNumeral`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum NumeralFault {
+    /// There are no letters at all
+    Empty,
+    /// A character is not one of the letters used as numerals
+    NotANumeral(char),
+    /// A letter is out of order, or repeats a magnitude already written (e.g.,
+    /// two letters for tens)
+    OutOfOrder(char),
+    /// With `ThousandsStyle::Repeated`, something other than the letter for 1,000
+    /// is found among the thousands
+    MixedThousands(char),
+}
+
+impl fmt::Display for NumeralFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no letters"),
+            Self::NotANumeral(c) => write!(f, "{} is not a numeral", c.escape_unicode()),
+            Self::OutOfOrder(c) => write!(f, "{} is out of order", c.escape_unicode()),
+            Self::MixedThousands(c) => {
+                write!(f, "{} is among repeated thousands", c.escape_unicode())
+            }
+        }
+    }
+}
+
+/// This writes a number in _abjad_ notation, e.g., 1,295 as غرصه. Each of the
+/// units, tens, and hundreds is represented by a single letter, and thousands
+/// according to `NumeralPrefs::thousands`. Zero has no representation, so in that
+/// case this returns `None`. It also returns `None` if a custom table lacks a
+/// letter for some component.
+#[must_use]
+pub fn to_abjad_numeral(number: u32, prefs: NumeralPrefs) -> Option<String> {
+    if number == 0 {
+        return None;
+    }
+
+    let mut written = String::new();
+
+    let thousands = number / 1000;
+    let remainder = number % 1000;
+
+    if thousands > 0 {
+        written.push_str(&write_thousands(thousands, prefs)?);
+    }
+
+    for (digit, magnitude) in [
+        (remainder / 100, 100),
+        (remainder / 10 % 10, 10),
+        (remainder % 10, 1),
+    ] {
+        if digit > 0 {
+            written.push(letter_for_value(digit * magnitude, prefs.letter_order)?);
+        }
+    }
+
+    // Ascending order is the reverse of descending order, down to the multiplier
+    if prefs.component_order == ComponentOrder::Ascending {
+        written = written.chars().rev().collect();
+    }
+
+    Some(written)
+}
+
+fn write_thousands(thousands: u32, prefs: NumeralPrefs) -> Option<String> {
+    let thousand = letter_for_value(1000, prefs.letter_order)?;
+
+    let written = match prefs.thousands {
+        ThousandsStyle::Repeated => std::iter::repeat_n(thousand, thousands as usize).collect(),
+        ThousandsStyle::Multiplier if thousands == 1 => thousand.to_string(),
+        ThousandsStyle::Multiplier => {
+            // The multiplier precedes the letter for 1,000 (until the whole
+            // numeral is reversed for ascending order)
+            let multiplier = NumeralPrefs {
+                component_order: ComponentOrder::Descending,
+                ..prefs
+            };
+
+            let mut written = to_abjad_numeral(thousands, multiplier)?;
+            written.push(thousand);
+            written
+        }
+    };
+
+    Some(written)
+}
+
+// In the standard tables, every value that is a single digit times a power of
+// ten, up to 1,000, has a letter
+fn letter_for_value(value: u32, letter_order: LetterOrder) -> Option<char> {
+    let table = letter_order.table();
+
+    ABJAD_LETTERS
+        .into_iter()
+        .find(|&letter| table.value(letter) == Some(value))
+}
+
+/// This reads a number in _abjad_ notation, e.g., غرصه as 1,295, following the
+/// conventions set in `NumeralPrefs` (as for `to_abjad_numeral`). Each magnitude
+/// of units, tens, and hundreds may be written only once, and only in the right
+/// order; multiples of 1,000 are read according to `NumeralPrefs::thousands`.
+/// Spaces, zero-width non-joiners, and _kashida_ between letters are ignored.
+///
+/// In ascending order, a numeral is read as the reverse of one in descending
+/// order, so that a multiplier of thousands follows the letter for 1,000: ثغب is
+/// 2,500, and بثغ is 1,502.
+///
+/// # Errors
+/// This returns `AbjadError::MalformedNumeral` if the input is empty, contains
+/// anything other than numeral letters, or is not well-formed; and
+/// `AbjadError::Overflow` if the number would exceed `u32::MAX`.
+pub fn parse_abjad_numeral(input: &str, prefs: NumeralPrefs) -> Result<u32, AbjadError> {
+    let mut digits: Vec<Digit> = Vec::new();
+
+    for (char_index, (byte_offset, character)) in input.char_indices().enumerate() {
+        if matches!(character, ' ' | '\u{200C}' | '\u{0640}') {
+            continue;
+        }
+
+        let Some(value) = numeral_value(character, prefs.letter_order) else {
+            return Err(AbjadError::MalformedNumeral {
+                fault: NumeralFault::NotANumeral(character),
+                byte_offset,
+                char_index,
+            });
+        };
+
+        digits.push(Digit {
+            character,
+            value,
+            byte_offset,
+            char_index,
+        });
+    }
+
+    if digits.is_empty() {
+        return Err(AbjadError::MalformedNumeral {
+            fault: NumeralFault::Empty,
+            byte_offset: 0,
+            char_index: 0,
+        });
+    }
+
+    // Ascending order is the reverse of descending order, down to the multiplier
+    if prefs.component_order == ComponentOrder::Ascending {
+        digits.reverse();
+    }
+
+    parse_digits(&digits, prefs)
+}
+
+// A letter in a numeral, along with its position in the input
+#[derive(Clone, Copy)]
+struct Digit {
+    character: char,
+    value: u32,
+    byte_offset: usize,
+    char_index: usize,
+}
+
+impl Digit {
+    const fn is_thousand(&self) -> bool {
+        self.value == 1000
+    }
+
+    // Units, tens, and hundreds are ranked 0, 1, and 2
+    const fn rank(&self) -> u32 {
+        self.value.ilog10()
+    }
+
+    const fn error(&self, fault: NumeralFault) -> AbjadError {
+        AbjadError::MalformedNumeral {
+            fault,
+            byte_offset: self.byte_offset,
+            char_index: self.char_index,
+        }
+    }
+}
+
+// Digits in descending order (those of an ascending numeral having been reversed)
+fn parse_digits(digits: &[Digit], prefs: NumeralPrefs) -> Result<u32, AbjadError> {
+    // Split off the thousands (the multiplier and the letter for 1,000) from the rest
+    let Some(position) = digits.iter().rposition(Digit::is_thousand) else {
+        return parse_components(digits);
+    };
+
+    let (multiplier, thousand) = (&digits[..position], digits[position]);
+    let rest = parse_components(&digits[position + 1..])?;
+
+    let thousands = match prefs.thousands {
+        ThousandsStyle::Multiplier if multiplier.is_empty() => 1,
+        ThousandsStyle::Multiplier => parse_digits(multiplier, prefs)?,
+        ThousandsStyle::Repeated => {
+            if let Some(digit) = multiplier.iter().find(|digit| !digit.is_thousand()) {
+                return Err(digit.error(NumeralFault::MixedThousands(digit.character)));
+            }
+
+            u32::try_from(multiplier.len() + 1).unwrap_or(u32::MAX)
+        }
+    };
+
+    thousands
+        .checked_mul(1000)
+        .and_then(|value| value.checked_add(rest))
+        .ok_or(AbjadError::Overflow {
+            byte_offset: thousand.byte_offset,
+            char_index: thousand.char_index,
+        })
+}
+
+// Units, tens, and hundreds, each written at most once, in descending order
+fn parse_components(digits: &[Digit]) -> Result<u32, AbjadError> {
+    let mut total: u32 = 0;
+    let mut last_rank: Option<u32> = None;
+
+    for digit in digits {
+        let in_order = last_rank.map_or_else(|| !digit.is_thousand(), |last| digit.rank() < last);
+
+        if !in_order {
+            return Err(digit.error(NumeralFault::OutOfOrder(digit.character)));
+        }
+
+        total += digit.value;
+        last_rank = Some(digit.rank());
+    }
+
+    Ok(total)
+}
+
+// Letters in numerals are the 28 letters of the alphabet, and common variants
+fn numeral_value(character: char, letter_order: LetterOrder) -> Option<u32> {
+    let letter = match character {
+        'ی' | 'ى' => 'ي',
+        'ک' => 'ك',
+        c => c,
+    };
+
+    if !ABJAD_LETTERS.contains(&letter) {
+        return None;
+    }
+
+    // With a custom table, a letter may have a value that cannot be a numeral
+    letter_order
+        .table()
+        .value(letter)
+        .filter(|&value| value == 1000 || (1..1000).contains(&value) && is_digit_value(value))
+}
+
+// A single digit times a power of ten
+const fn is_digit_value(value: u32) -> bool {
+    value.is_multiple_of(10_u32.pow(value.ilog10()))
+}
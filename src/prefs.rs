@@ -0,0 +1,361 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{AbjadError, AbjadPrefs, CalculationMode, LetterOrder, NameSpelling, Orthography};
+
+/// This `enum` names sets of preferences suited to common traditions. Each can
+/// be turned into `AbjadPrefs` with `prefs` (or `into`), or used as the starting
+/// point of a builder with `builder`. Presets can also be parsed from, and
+/// written as, their names in kebab case (e.g., `persian-chronogram`).
+///
+/// All presets ignore diacritics, whitespace, joiners, _kashida_, and
+/// punctuation, so that vocalized text and multi-line poems can be checked
+/// strictly; they differ as described for each.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Preset {
+    /// Arabic text, valued in Mashriqi order
+    ClassicalArabic,
+    /// As `ClassicalArabic`, but in Maghribi order
+    Maghribi,
+    /// As `PersianChronogram`, but with the Ottoman Turkish letters
+    OttomanTarih,
+    /// Persian text, as in chronograms (_maddah tarikh_): as `ClassicalArabic`,
+    /// but with the lone _hamzah_, which is not a letter in Persian, ignored
+    PersianChronogram,
+    /// Qur'anic text: as `ClassicalArabic`, but with digits (such as verse
+    /// numbers) also ignored
+    Quranic,
+}
+
+impl Preset {
+    /// All of the presets
+    pub const ALL: [Self; 5] = [
+        Self::ClassicalArabic,
+        Self::Maghribi,
+        Self::OttomanTarih,
+        Self::PersianChronogram,
+        Self::Quranic,
+    ];
+
+    /// The preferences for this preset
+    #[must_use]
+    pub fn prefs(self) -> AbjadPrefs {
+        let classical = AbjadPrefs {
+            ignore_diacritics: true,
+            ignore_whitespace: true,
+            ignore_joiners: true,
+            ignore_kashida: true,
+            ignore_punctuation: true,
+            ..AbjadPrefs::default()
+        };
+
+        match self {
+            Self::ClassicalArabic => classical,
+            Self::Maghribi => AbjadPrefs {
+                letter_order: LetterOrder::Maghribi,
+                ..classical
+            },
+            Self::OttomanTarih => AbjadPrefs {
+                orthography: Orthography::Ottoman,
+                ignore_lone_hamzah: true,
+                ..classical
+            },
+            Self::PersianChronogram => AbjadPrefs {
+                ignore_lone_hamzah: true,
+                ..classical
+            },
+            Self::Quranic => AbjadPrefs {
+                ignore_digits: true,
+                ..classical
+            },
+        }
+    }
+
+    /// A builder starting from this preset
+    #[must_use]
+    pub fn builder(self) -> AbjadPrefsBuilder {
+        AbjadPrefsBuilder {
+            prefs: self.prefs(),
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::ClassicalArabic => "classical-arabic",
+            Self::Maghribi => "maghribi",
+            Self::OttomanTarih => "ottoman-tarih",
+            Self::PersianChronogram => "persian-chronogram",
+            Self::Quranic => "quranic",
+        }
+    }
+}
+
+impl From<Preset> for AbjadPrefs {
+    fn from(preset: Preset) -> Self {
+        preset.prefs()
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = AbjadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| AbjadError::UnknownName(s.to_string()))
+    }
+}
+
+impl fmt::Display for LetterOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Maghribi => f.write_str("maghribi"),
+            Self::Mashriqi => f.write_str("mashriqi"),
+            Self::Custom(_) => f.write_str("custom"),
+        }
+    }
+}
+
+/// Only `mashriqi` and `maghribi` can be parsed (case-insensitively). A custom
+/// table must be parsed as an `AbjadTable`.
+impl FromStr for LetterOrder {
+    type Err = AbjadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "maghribi" => Ok(Self::Maghribi),
+            "mashriqi" => Ok(Self::Mashriqi),
+            _ => Err(AbjadError::UnknownName(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Orthography {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Persian => f.write_str("persian"),
+            Self::Urdu => f.write_str("urdu"),
+            Self::Ottoman => f.write_str("ottoman"),
+        }
+    }
+}
+
+impl FromStr for Orthography {
+    type Err = AbjadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "persian" => Ok(Self::Persian),
+            "urdu" => Ok(Self::Urdu),
+            "ottoman" => Ok(Self::Ottoman),
+            _ => Err(AbjadError::UnknownName(s.to_string())),
+        }
+    }
+}
+
+/// _Abjad malfuzi_ is written `malfuzi` with plain names, and `malfuzi-hamzated`
+/// with hamzated names.
+impl fmt::Display for CalculationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Kabir => f.write_str("kabir"),
+            Self::Saghir => f.write_str("saghir"),
+            Self::Ausat => f.write_str("ausat"),
+            Self::Malfuzi(NameSpelling::Plain) => f.write_str("malfuzi"),
+            Self::Malfuzi(NameSpelling::Hamzated) => f.write_str("malfuzi-hamzated"),
+        }
+    }
+}
+
+impl FromStr for CalculationMode {
+    type Err = AbjadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "kabir" => Ok(Self::Kabir),
+            "saghir" => Ok(Self::Saghir),
+            "ausat" => Ok(Self::Ausat),
+            "malfuzi" => Ok(Self::Malfuzi(NameSpelling::Plain)),
+            "malfuzi-hamzated" => Ok(Self::Malfuzi(NameSpelling::Hamzated)),
+            _ => Err(AbjadError::UnknownName(s.to_string())),
+        }
+    }
+}
+
+// With serde, a mode is written by name, as by `Display`
+#[cfg(feature = "serde")]
+impl serde::Serialize for CalculationMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CalculationMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A builder for `AbjadPrefs`, starting from the defaults (with
+/// `AbjadPrefs::builder`), from a preset (with `Preset::builder`), or from other
+/// preferences (with `from`). Each method sets the field of the same name.
+/// Unlike setting the fields directly, `build` checks that the preferences make
+/// sense together.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AbjadPrefsBuilder {
+    prefs: AbjadPrefs,
+}
+
+impl AbjadPrefsBuilder {
+    /// Sets `count_shaddah`
+    #[must_use]
+    pub const fn count_shaddah(mut self, count: bool) -> Self {
+        self.prefs.count_shaddah = count;
+        self
+    }
+
+    /// Sets `double_alif_maddah`
+    #[must_use]
+    pub const fn double_alif_maddah(mut self, double: bool) -> Self {
+        self.prefs.double_alif_maddah = double;
+        self
+    }
+
+    /// Sets `ignore_lone_hamzah`
+    #[must_use]
+    pub const fn ignore_lone_hamzah(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_lone_hamzah = ignore;
+        self
+    }
+
+    /// Sets `letter_order`
+    #[must_use]
+    pub const fn letter_order(mut self, letter_order: LetterOrder) -> Self {
+        self.prefs.letter_order = letter_order;
+        self
+    }
+
+    /// Sets `orthography`
+    #[must_use]
+    pub const fn orthography(mut self, orthography: Orthography) -> Self {
+        self.prefs.orthography = orthography;
+        self
+    }
+
+    /// Sets `fold_presentation_forms`
+    #[must_use]
+    pub const fn fold_presentation_forms(mut self, fold: bool) -> Self {
+        self.prefs.fold_presentation_forms = fold;
+        self
+    }
+
+    /// Sets `ignore_diacritics`
+    #[must_use]
+    pub const fn ignore_diacritics(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_diacritics = ignore;
+        self
+    }
+
+    /// Sets `count_superscript_alif`
+    #[must_use]
+    pub const fn count_superscript_alif(mut self, count: bool) -> Self {
+        self.prefs.count_superscript_alif = count;
+        self
+    }
+
+    /// Sets `ignore_whitespace`
+    #[must_use]
+    pub const fn ignore_whitespace(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_whitespace = ignore;
+        self
+    }
+
+    /// Sets `ignore_joiners`
+    #[must_use]
+    pub const fn ignore_joiners(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_joiners = ignore;
+        self
+    }
+
+    /// Sets `ignore_kashida`
+    #[must_use]
+    pub const fn ignore_kashida(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_kashida = ignore;
+        self
+    }
+
+    /// Sets `ignore_punctuation`
+    #[must_use]
+    pub const fn ignore_punctuation(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_punctuation = ignore;
+        self
+    }
+
+    /// Sets `ignore_digits`
+    #[must_use]
+    pub const fn ignore_digits(mut self, ignore: bool) -> Self {
+        self.prefs.ignore_digits = ignore;
+        self
+    }
+
+    /// Sets `mode`
+    #[must_use]
+    pub const fn mode(mut self, mode: CalculationMode) -> Self {
+        self.prefs.mode = mode;
+        self
+    }
+
+    /// This checks the preferences and returns them.
+    ///
+    /// # Errors
+    /// This returns `AbjadError::InvalidPrefs` if a custom table gives some letter
+    /// no value, or if _abjad ausat_ is used with a custom table in which two
+    /// letters share a value (so that their positions are undefined).
+    pub fn build(self) -> Result<AbjadPrefs, AbjadError> {
+        if let LetterOrder::Custom(table) = self.prefs.letter_order {
+            let sequence = table.sequence();
+
+            if let Some((_, letter)) = sequence.iter().find(|&&(value, _)| value == 0) {
+                return Err(AbjadError::InvalidPrefs(format!(
+                    "the custom table gives {letter} no value"
+                )));
+            }
+
+            let shared = sequence.windows(2).find(|pair| pair[0].0 == pair[1].0);
+
+            if let (CalculationMode::Ausat, Some(pair)) = (self.prefs.mode, shared) {
+                return Err(AbjadError::InvalidPrefs(format!(
+                    "abjad ausat needs distinct values, but {} and {} share one",
+                    pair[0].1, pair[1].1
+                )));
+            }
+        }
+
+        Ok(self.prefs)
+    }
+}
+
+impl From<AbjadPrefs> for AbjadPrefsBuilder {
+    fn from(prefs: AbjadPrefs) -> Self {
+        Self { prefs }
+    }
+}
+
+impl AbjadPrefs {
+    /// A builder starting from the default preferences
+    #[must_use]
+    pub fn builder() -> AbjadPrefsBuilder {
+        AbjadPrefsBuilder::default()
+    }
+}
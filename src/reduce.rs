@@ -0,0 +1,161 @@
+use crate::{Abjad, AbjadPrefs, LetterOrder};
+
+/// This `enum` names the schemes by which an _abjad_ total may be reduced. In
+/// each case but `DigitalRoot`, the total is taken modulo some number, with a
+/// remainder of zero counted as that number. A total of zero (as for empty
+/// text) is reduced to zero in every scheme, with no letter, mansion, or sign.
+/// (Per-letter reductions, such as _abjad ausat_, are available as a
+/// `CalculationMode`.)
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Reduction {
+    /// The digital root (repeated sum of decimal digits), equivalent to reduction
+    /// modulo 9: from 1 to 9, or 0 for a total of 0
+    DigitalRoot,
+    /// Reduction modulo 28, from 1 to 28, giving a letter of the alphabet (by its
+    /// position in the _abjad_ sequence) and a lunar mansion
+    Mansions,
+    /// Reduction modulo 12, as for _abjad saghir_, from 1 to 12, giving a sign of
+    /// the zodiac (from الحمل, Aries)
+    Saghir,
+    /// Reduction modulo 30, from 1 to 30, as for a day of the lunar month
+    Thirty,
+}
+
+/// The result of a `Reduction`: the reduced value and, where relevant, what it
+/// corresponds to. With the `serde` feature, this can be serialized but not
+/// deserialized, since the names are static.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Reduced {
+    /// The reduced value
+    pub value: u32,
+
+    /// The letter at this position in the _abjad_ sequence (for `Mansions`)
+    pub letter: Option<char>,
+
+    /// The lunar mansion (_manzil_) at this position, by its Arabic name (for
+    /// `Mansions`)
+    pub mansion: Option<&'static str>,
+
+    /// The sign of the zodiac (_burj_) at this position, by its Arabic name (for
+    /// `Saghir`)
+    pub sign: Option<&'static str>,
+}
+
+impl Reduction {
+    /// This reduces a total according to the scheme. For `Mansions`, the letter
+    /// is found in the Mashriqi sequence.
+    #[must_use]
+    pub fn reduce(self, total: u64) -> Reduced {
+        self.reduce_in_order(total, LetterOrder::Mashriqi)
+    }
+
+    /// This calculates the total of a text with `abjad_wide`, ignoring
+    /// unrecognized characters, and reduces it according to the scheme. For
+    /// `Mansions`, the letter is found in the sequence of the chosen letter order.
+    #[must_use]
+    pub fn reduce_text(self, text: &str, prefs: AbjadPrefs) -> Reduced {
+        self.reduce_in_order(text.abjad_wide(prefs), prefs.letter_order)
+    }
+
+    fn reduce_in_order(self, total: u64, letter_order: LetterOrder) -> Reduced {
+        let mut reduced = Reduced {
+            value: 0,
+            letter: None,
+            mansion: None,
+            sign: None,
+        };
+
+        if total == 0 {
+            return reduced;
+        }
+
+        let value = match self {
+            Self::DigitalRoot => cycle(total, 9),
+            Self::Mansions => cycle(total, 28),
+            Self::Saghir => cycle(total, 12),
+            Self::Thirty => cycle(total, 30),
+        };
+
+        reduced.value = value;
+
+        match self {
+            Self::Mansions => {
+                reduced.letter = letter_at(value, letter_order);
+                reduced.mansion = MANSIONS.get(value as usize - 1).copied();
+            }
+            Self::Saghir => reduced.sign = SIGNS.get(value as usize - 1).copied(),
+            Self::DigitalRoot | Self::Thirty => {}
+        }
+
+        reduced
+    }
+}
+
+// Reduce modulo n, counting a remainder of zero as n
+fn cycle(total: u64, n: u64) -> u32 {
+    let remainder = match total % n {
+        0 => n,
+        remainder => remainder,
+    };
+
+    u32::try_from(remainder).unwrap_or_default()
+}
+
+// The letter at a position (from 1 to 28) in the abjad sequence
+fn letter_at(position: u32, letter_order: LetterOrder) -> Option<char> {
+    let sequence = letter_order.table().sequence();
+    sequence
+        .get(position as usize - 1)
+        .map(|&(_, letter)| letter)
+}
+
+// The lunar mansions, from al-Sharatan
+const MANSIONS: [&str; 28] = [
+    "الشرطين",
+    "البطين",
+    "الثريا",
+    "الدبران",
+    "الهقعة",
+    "الهنعة",
+    "الذراع",
+    "النثرة",
+    "الطرف",
+    "الجبهة",
+    "الزبرة",
+    "الصرفة",
+    "العواء",
+    "السماك",
+    "الغفر",
+    "الزبانى",
+    "الإكليل",
+    "القلب",
+    "الشولة",
+    "النعائم",
+    "البلدة",
+    "سعد الذابح",
+    "سعد بلع",
+    "سعد السعود",
+    "سعد الأخبية",
+    "الفرغ المقدم",
+    "الفرغ المؤخر",
+    "بطن الحوت",
+];
+
+// The signs of the zodiac, from Aries
+const SIGNS: [&str; 12] = [
+    "الحمل",
+    "الثور",
+    "الجوزاء",
+    "السرطان",
+    "الأسد",
+    "السنبلة",
+    "الميزان",
+    "العقرب",
+    "القوس",
+    "الجدي",
+    "الدلو",
+    "الحوت",
+];
@@ -0,0 +1,107 @@
+use crate::{BreakdownEntry, Romanization};
+
+/// The romanized label of a letter: its name and the symbol by which it is
+/// transliterated, in a given scheme (e.g., ص as ṣād, ṣ). As with `Reduced`, the
+/// `serde` feature allows this to be serialized but not deserialized.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LetterLabel {
+    /// The name of the letter (e.g., ṣād)
+    pub name: &'static str,
+
+    /// The symbol for the letter (e.g., ṣ). This is empty for _alif waṣlah_,
+    /// which is not pronounced.
+    pub symbol: &'static str,
+}
+
+/// This returns the label of a letter in the given scheme of romanization, or
+/// `None` if the character is not one of the letters that can be valued. All of
+/// the letters of the supported orthographies (Arabic, Persian, Urdu, and Ottoman
+/// Turkish) are covered, including variants such as أ, ة, and ى; diacritics and
+/// presentation forms are not.
+///
+/// The schemes differ in the symbols for ث ج خ ذ ش غ (and چ ژ), which DMG writes
+/// with single letters (ṯ ǧ ḫ ḏ š ġ č ž), and in those for ة and ى, which ALA-LC
+/// writes as h and á.
+#[must_use]
+pub const fn letter_label(letter: char, romanization: Romanization) -> Option<LetterLabel> {
+    let dmg = matches!(romanization, Romanization::Dmg);
+    let ala_lc = matches!(romanization, Romanization::AlaLc);
+
+    let (name, symbol) = match letter {
+        'ا' => ("alif", "ā"),
+        'أ' | 'إ' => ("alif hamzah", "ʾ"),
+        'ٱ' => ("alif waṣlah", ""),
+        'آ' => ("alif maddah", "ʾā"),
+        'ء' => ("hamzah", "ʾ"),
+        'ب' => ("bāʾ", "b"),
+        'پ' => ("pe", "p"),
+        'ت' => ("tāʾ", "t"),
+        'ٹ' => ("ṭe", "ṭ"),
+        'ث' if dmg => ("ṯāʾ", "ṯ"),
+        'ث' => ("thāʾ", "th"),
+        'ج' if dmg => ("ǧīm", "ǧ"),
+        'ج' => ("jīm", "j"),
+        'چ' if dmg => ("če", "č"),
+        'چ' => ("che", "ch"),
+        'ح' => ("ḥāʾ", "ḥ"),
+        'خ' if dmg => ("ḫāʾ", "ḫ"),
+        'خ' => ("khāʾ", "kh"),
+        'د' => ("dāl", "d"),
+        'ڈ' => ("ḍāl", "ḍ"),
+        'ذ' if dmg => ("ḏāl", "ḏ"),
+        'ذ' => ("dhāl", "dh"),
+        'ر' => ("rāʾ", "r"),
+        'ڑ' => ("ṛe", "ṛ"),
+        'ز' => ("zāy", "z"),
+        'ژ' if dmg => ("že", "ž"),
+        'ژ' => ("zhe", "zh"),
+        'س' => ("sīn", "s"),
+        'ش' if dmg => ("šīn", "š"),
+        'ش' => ("shīn", "sh"),
+        'ص' => ("ṣād", "ṣ"),
+        'ض' => ("ḍād", "ḍ"),
+        'ط' => ("ṭāʾ", "ṭ"),
+        'ظ' => ("ẓāʾ", "ẓ"),
+        'ع' => ("ʿayn", "ʿ"),
+        'غ' if dmg => ("ġayn", "ġ"),
+        'غ' => ("ghayn", "gh"),
+        'ف' => ("fāʾ", "f"),
+        'ق' => ("qāf", "q"),
+        'ك' | 'ک' => ("kāf", "k"),
+        'گ' => ("gāf", "g"),
+        'ڭ' | 'ݣ' => ("sağır kef", "ñ"),
+        'ل' => ("lām", "l"),
+        'م' => ("mīm", "m"),
+        'ن' => ("nūn", "n"),
+        'ں' => ("nūn ghunnah", "ṉ"),
+        'ه' => ("hāʾ", "h"),
+        'ہ' => ("choṭī he", "h"),
+        'ھ' => ("do-chashmī he", "h"),
+        'ة' | 'ۃ' if ala_lc => ("tāʾ marbūṭah", "h"),
+        'ة' | 'ۃ' => ("tāʾ marbūṭah", "a"),
+        'ۀ' | 'ۂ' => ("he with hamzah", "-yi"),
+        'و' => ("wāw", "w"),
+        'ۋ' => ("ve", "v"),
+        'ؤ' => ("wāw hamzah", "ʾ"),
+        'ي' | 'ی' => ("yāʾ", "y"),
+        'ئ' => ("yāʾ hamzah", "ʾ"),
+        'ى' if ala_lc => ("alif maqṣūrah", "á"),
+        'ى' => ("alif maqṣūrah", "ā"),
+        'ے' => ("baṛī ye", "e"),
+        'ۓ' => ("baṛī ye with hamzah", "ʾe"),
+        _ => return None,
+    };
+
+    Some(LetterLabel { name, symbol })
+}
+
+impl BreakdownEntry {
+    /// The label of the character, as by `letter_label`, for showing a breakdown
+    /// to readers of romanized text. This is `None` for anything other than a
+    /// letter.
+    #[must_use]
+    pub const fn label(&self, romanization: Romanization) -> Option<LetterLabel> {
+        letter_label(self.character, romanization)
+    }
+}
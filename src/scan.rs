@@ -0,0 +1,131 @@
+use std::ops::RangeInclusive;
+
+use crate::{AbjadPrefs, AbjadWords};
+
+/// A span of consecutive words found by `SpanScan`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Span<'a> {
+    /// The text of the span, from the start of its first word to the end of its
+    /// last
+    pub text: &'a str,
+
+    /// The byte offset of the start of the span in the input
+    pub start: usize,
+
+    /// The byte offset of the end of the span in the input (exclusive)
+    pub end: usize,
+
+    /// The number of words in the span
+    pub words: usize,
+
+    /// The total _abjad_ value of the span
+    pub value: u32,
+}
+
+/// This scans a text for spans of consecutive words whose total _abjad_ value
+/// falls in a given range (which may be a single value), as in searching for
+/// chronograms hidden in a poem. It is an iterator over the spans found, in order
+/// of their start, and then of their end.
+///
+/// Words are found, and valued, as by `AbjadWords`, ignoring unrecognized
+/// characters, so the value of a span is the sum of the values of its words.
+/// Spans beginning or ending with a word of no value are skipped, as they would
+/// only repeat a shorter span.
+#[derive(Clone, Debug)]
+pub struct SpanScan<'a> {
+    text: &'a str,
+    words: Vec<(usize, usize, u32)>,
+    values: RangeInclusive<u32>,
+    max_words: usize,
+    start: usize,
+    len: usize,
+    sum: u64,
+}
+
+impl<'a> SpanScan<'a> {
+    /// This prepares a scan of `text` for spans of up to `max_words` words whose
+    /// total is in `values`, with words valued according to `prefs`.
+    #[must_use]
+    pub fn new(
+        text: &'a str,
+        values: RangeInclusive<u32>,
+        max_words: usize,
+        prefs: AbjadPrefs,
+    ) -> Self {
+        let words = AbjadWords::new(text, prefs)
+            .map(|word| (word.start, word.end, word.value))
+            .collect();
+
+        Self {
+            text,
+            words,
+            values,
+            max_words,
+            start: 0,
+            len: 0,
+            sum: 0,
+        }
+    }
+
+    // Move on to spans beginning with the next word
+    const fn advance_start(&mut self) {
+        self.start += 1;
+        self.len = 0;
+        self.sum = 0;
+    }
+}
+
+impl<'a> Iterator for SpanScan<'a> {
+    type Item = Span<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.start < self.words.len() {
+            let end = self.start + self.len;
+
+            if self.len >= self.max_words || end >= self.words.len() {
+                self.advance_start();
+                continue;
+            }
+
+            let (_, _, first_value) = self.words[self.start];
+
+            if first_value == 0 {
+                self.advance_start();
+                continue;
+            }
+
+            let (_, word_end, word_value) = self.words[end];
+
+            self.sum += u64::from(word_value);
+            self.len += 1;
+
+            // Values never decrease as a span grows
+            if self.sum > u64::from(*self.values.end()) {
+                self.advance_start();
+                continue;
+            }
+
+            if word_value == 0 {
+                continue;
+            }
+
+            // The sum is no greater than a u32 here
+            let value = u32::try_from(self.sum).unwrap_or(u32::MAX);
+
+            if self.values.contains(&value) {
+                let (span_start, _, _) = self.words[self.start];
+
+                return Some(Span {
+                    text: &self.text[span_start..word_end],
+                    start: span_start,
+                    end: word_end,
+                    words: self.len,
+                    value,
+                });
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Abjad, AbjadPrefs};
+
+/// Options for `ChronogramSearch`, which bound the search.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SearchPrefs {
+    /// The largest number of words in a combination (3 by default). The search
+    /// grows rapidly with this number, so it should be kept small.
+    pub max_words: usize,
+
+    /// Allow the same word to appear more than once in a combination? This is
+    /// false by default.
+    pub allow_repeats: bool,
+}
+
+impl Default for SearchPrefs {
+    fn default() -> Self {
+        Self {
+            max_words: 3,
+            allow_repeats: false,
+        }
+    }
+}
+
+/// This searches a lexicon for combinations of words whose total _abjad_ value
+/// is a given target, as in composing a chronogram. It is an iterator, so
+/// combinations are found only as they are requested.
+///
+/// Each word is valued separately with `abjad_strict`; words that cannot be
+/// valued (or whose value is zero) are left out. Combinations are unordered, and
+/// each is yielded only once, with its words in ascending order of value.
+#[derive(Clone, Debug)]
+pub struct ChronogramSearch<'a> {
+    words: Vec<(&'a str, u32)>,
+    target: u32,
+    prefs: SearchPrefs,
+    chosen: Vec<usize>,
+    sum: u32,
+    next: usize,
+}
+
+impl<'a> ChronogramSearch<'a> {
+    /// This prepares a search of `lexicon` for combinations of words whose total
+    /// is `target`, with words valued according to `prefs`.
+    pub fn new(
+        lexicon: impl IntoIterator<Item = &'a str>,
+        target: u32,
+        prefs: AbjadPrefs,
+        search_prefs: SearchPrefs,
+    ) -> Self {
+        let mut words: Vec<(&'a str, u32)> = lexicon
+            .into_iter()
+            .filter_map(|word| match word.abjad_strict(prefs) {
+                Ok(value) if value > 0 => Some((word, value)),
+                _ => None,
+            })
+            .collect();
+
+        // Sorting by value allows the search to stop early
+        words.sort_unstable_by_key(|&(word, value)| (value, word));
+        words.dedup();
+
+        Self {
+            words,
+            target,
+            prefs: search_prefs,
+            chosen: Vec::new(),
+            sum: 0,
+            next: 0,
+        }
+    }
+
+    // Remove the last word chosen, and move on to the next candidate in its place
+    fn backtrack(&mut self) -> bool {
+        let Some(index) = self.chosen.pop() else {
+            return false;
+        };
+
+        self.sum -= self.words[index].1;
+        self.next = index + 1;
+
+        true
+    }
+
+    // Could the remaining slots, filled with the most valuable word, reach the target?
+    fn can_reach_target(&self) -> bool {
+        let max_value = self.words.last().map_or(0, |&(_, value)| u64::from(value));
+        let slots = (self.prefs.max_words - self.chosen.len()) as u64;
+
+        u64::from(self.sum) + slots * max_value >= u64::from(self.target)
+    }
+}
+
+impl<'a> Iterator for ChronogramSearch<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.next >= self.words.len() || self.chosen.len() >= self.prefs.max_words {
+                if self.backtrack() {
+                    continue;
+                }
+
+                return None;
+            }
+
+            let index = self.next;
+            let new_sum = self.sum.saturating_add(self.words[index].1);
+
+            // Every later candidate is at least as valuable
+            if new_sum > self.target {
+                self.next = self.words.len();
+                continue;
+            }
+
+            self.chosen.push(index);
+            self.sum = new_sum;
+            self.next = if self.prefs.allow_repeats {
+                index
+            } else {
+                index + 1
+            };
+
+            if self.sum == self.target {
+                let found = self.chosen.iter().map(|&i| self.words[i].0).collect();
+                self.backtrack();
+                return Some(found);
+            }
+
+            if !self.can_reach_target() {
+                self.backtrack();
+            }
+        }
+    }
+}
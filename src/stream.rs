@@ -0,0 +1,180 @@
+use std::io::{self, Read};
+
+use crate::{AbjadError, AbjadPrefs, LetterRule, LetterValuer, UnrecognizedChar};
+
+/// An incremental calculator, for input too large to hold in memory. Text is
+/// fed in chunks, as `&str` (with `feed`), as bytes (with `feed_bytes`), or from
+/// any `io::Read` or `io::BufRead` (with `feed_reader`), and the result is taken
+/// with `finish`. Chunks may split the input anywhere: the value of the last
+/// letter is carried over for a _shaddah_ at the start of the next chunk, and a
+/// UTF-8 sequence split between chunks of bytes is put back together.
+///
+/// The total is summed into a `u64`, as by `abjad_wide`, and unrecognized
+/// characters are recorded as by `abjad_collect_errors`, with their positions
+/// counted from the start of the stream.
+#[derive(Clone, Debug)]
+pub struct AbjadAccumulator {
+    valuer: LetterValuer,
+    total: u64,
+    errors: Vec<UnrecognizedChar>,
+    byte_offset: usize,
+    char_index: usize,
+    // The start of a character split between chunks of bytes
+    pending: Vec<u8>,
+}
+
+impl AbjadAccumulator {
+    /// This prepares to value a stream of text according to `prefs`.
+    #[must_use]
+    pub const fn new(prefs: AbjadPrefs) -> Self {
+        Self {
+            valuer: LetterValuer::new(prefs),
+            total: 0,
+            errors: Vec::new(),
+            byte_offset: 0,
+            char_index: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// This adds a chunk of text.
+    ///
+    /// # Errors
+    /// This returns `AbjadError::InvalidUtf8`, and does not add the chunk, if the
+    /// previous chunk of bytes ended partway through a character. (Finish feeding
+    /// the bytes first.)
+    pub fn feed(&mut self, chunk: &str) -> Result<(), AbjadError> {
+        if !self.pending.is_empty() {
+            return Err(self.invalid_utf8());
+        }
+
+        self.push_str(chunk);
+        Ok(())
+    }
+
+    /// This adds a chunk of UTF-8 bytes, which may begin or end partway through
+    /// a character.
+    ///
+    /// # Errors
+    /// This returns `AbjadError::InvalidUtf8` if the bytes are not valid UTF-8.
+    pub fn feed_bytes(&mut self, mut bytes: &[u8]) -> Result<(), AbjadError> {
+        if let Some(&lead) = self.pending.first() {
+            let needed = utf8_width(lead).saturating_sub(self.pending.len());
+            let (rest, remainder) = bytes.split_at(needed.min(bytes.len()));
+
+            self.pending.extend_from_slice(rest);
+            bytes = remainder;
+
+            if rest.len() < needed {
+                return Ok(());
+            }
+
+            let completed = std::mem::take(&mut self.pending);
+            let text = std::str::from_utf8(&completed).map_err(|_| self.invalid_utf8())?;
+            self.push_str(text);
+        }
+
+        match std::str::from_utf8(bytes) {
+            Ok(text) => self.push_str(text),
+            Err(error) => {
+                let (valid, invalid) = bytes.split_at(error.valid_up_to());
+                // The bytes up to the error are known to be valid
+                self.push_str(std::str::from_utf8(valid).unwrap_or_default());
+
+                if error.error_len().is_some() {
+                    return Err(self.invalid_utf8());
+                }
+
+                self.pending.extend_from_slice(invalid);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This reads everything from `reader`, in blocks of 64 KiB. (There is no
+    /// need to wrap it in an `io::BufReader`.)
+    ///
+    /// # Errors
+    /// This returns any error from `reader`, or an error of kind
+    /// `io::ErrorKind::InvalidData`, wrapping `AbjadError::InvalidUtf8`, if the
+    /// input is not valid UTF-8.
+    pub fn feed_reader(&mut self, mut reader: impl Read) -> io::Result<()> {
+        let mut buffer = vec![0; 64 * 1024];
+
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => return Ok(()),
+                Ok(read) => read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            self.feed_bytes(&buffer[..read])
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        }
+    }
+
+    /// The total so far
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The unrecognized characters found so far
+    #[must_use]
+    pub fn errors(&self) -> &[UnrecognizedChar] {
+        &self.errors
+    }
+
+    /// This ends the stream, returning the total and any unrecognized characters.
+    ///
+    /// # Errors
+    /// This returns `AbjadError::InvalidUtf8` if the stream ends partway through
+    /// a character.
+    pub fn finish(self) -> Result<(u64, Vec<UnrecognizedChar>), AbjadError> {
+        if !self.pending.is_empty() {
+            return Err(self.invalid_utf8());
+        }
+
+        Ok((self.total, self.errors))
+    }
+
+    fn push_str(&mut self, text: &str) {
+        for character in text.chars() {
+            self.push(character);
+        }
+    }
+
+    fn push(&mut self, character: char) {
+        let (new_value, rule) = self.valuer.value(character);
+
+        if rule == LetterRule::Unrecognized {
+            self.errors.push(UnrecognizedChar {
+                character,
+                byte_offset: self.byte_offset,
+                char_index: self.char_index,
+            });
+        }
+
+        self.total += u64::from(new_value);
+        self.byte_offset += character.len_utf8();
+        self.char_index += 1;
+    }
+
+    const fn invalid_utf8(&self) -> AbjadError {
+        AbjadError::InvalidUtf8 {
+            byte_offset: self.byte_offset,
+        }
+    }
+}
+
+// The length of a UTF-8 sequence, from its first byte
+const fn utf8_width(lead: u8) -> usize {
+    match lead {
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _ => 1,
+    }
+}
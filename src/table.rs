@@ -0,0 +1,216 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::AbjadError;
+
+// The 28 letters of the alphabet, in Mashriqi order
+pub const ABJAD_LETTERS: [char; 28] = [
+    'ا', 'ب', 'ج', 'د', 'ه', 'و', 'ز', 'ح', 'ط', 'ي', 'ك', 'ل', 'م', 'ن', 'س', 'ع', 'ف', 'ص', 'ق',
+    'ر', 'ش', 'ت', 'ث', 'خ', 'ذ', 'ض', 'ظ', 'غ',
+];
+
+/// A table of values for the 28 letters of the alphabet. The two standard
+/// tables are available as `AbjadTable::MASHRIQI` and `AbjadTable::MAGHRIBI`;
+/// others may be built from code with `new` or `with_value`, or parsed from text
+/// with `str::parse`. To use a table, pass it in `LetterOrder::Custom`.
+///
+/// Letters added in other languages, and variants such as أ or ة, take the
+/// value of the letter from which they derive, so they need not (and cannot) be
+/// listed separately.
+///
+/// The text format is a list of entries, one per line or separated by commas,
+/// each consisting of a letter, `=` or `:`, and a value. Letters may be quoted,
+/// and the list may be enclosed in braces. Blank lines, comments (from `#` to
+/// the end of the line), and section headers in square brackets are skipped.
+/// This means that a flat TOML table or JSON object can be read as is:
+///
+/// ```text
+/// # A table in which ش is 1,000
+/// "ش" = 1000 # as in the Maghribi table
+/// "غ" = 900
+/// ```
+///
+/// Letters not listed keep their Mashriqi values. The `Display` implementation
+/// writes a table in the same format, listing every letter.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct AbjadTable {
+    values: [u32; 28],
+}
+
+impl AbjadTable {
+    /// The standard Mashriqi table
+    pub const MASHRIQI: Self = Self::new([
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 200, 300, 400, 500,
+        600, 700, 800, 900, 1000,
+    ]);
+
+    /// The Maghribi table, in which the values of س ص ش ض ظ غ differ
+    pub const MAGHRIBI: Self = Self::new([
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 20, 30, 40, 50, 300, 70, 80, 60, 100, 200, 1000, 400, 500,
+        600, 700, 90, 800, 900,
+    ]);
+
+    /// This builds a table from the values of the 28 letters, given in Mashriqi
+    /// order (ا ب ج د ه و ز ح ط ي ك ل م ن س ع ف ص ق ر ش ت ث خ ذ ض ظ غ).
+    #[must_use]
+    pub const fn new(values: [u32; 28]) -> Self {
+        Self { values }
+    }
+
+    /// This returns a copy of the table with the value of one letter changed, or
+    /// `None` if the character is not one of the 28 letters of the alphabet.
+    #[must_use]
+    pub fn with_value(mut self, letter: char, value: u32) -> Option<Self> {
+        let index = ABJAD_LETTERS.iter().position(|&l| l == letter)?;
+        self.values[index] = value;
+        Some(self)
+    }
+
+    /// The value of a letter (or of a variant, such as أ or پ), or `None` if the
+    /// character is not a letter
+    #[must_use]
+    pub const fn value(&self, letter: char) -> Option<u32> {
+        match letter_index(letter) {
+            Some(index) => Some(self.values[index]),
+            None => None,
+        }
+    }
+
+    // The value of the letter at the given position in ABJAD_LETTERS
+    pub(crate) const fn value_at(&self, index: usize) -> u32 {
+        self.values[index]
+    }
+
+    // The letters, in ascending order of value (ties broken by Mashriqi order)
+    pub(crate) fn sequence(&self) -> Vec<(u32, char)> {
+        let mut sequence: Vec<(u32, char)> = self.values.into_iter().zip(ABJAD_LETTERS).collect();
+        sequence.sort_by_key(|&(value, _)| value);
+        sequence
+    }
+
+    // The position of a letter in the sequence of the table (from 1 to 28)
+    pub(crate) fn ordinal_at(&self, index: usize) -> u32 {
+        let value = self.values[index];
+        let lower = self
+            .values
+            .iter()
+            .enumerate()
+            .filter(|&(i, &v)| v < value || (v == value && i < index));
+
+        u32::try_from(lower.count()).unwrap_or_default() + 1
+    }
+}
+
+impl Default for AbjadTable {
+    fn default() -> Self {
+        Self::MASHRIQI
+    }
+}
+
+impl fmt::Display for AbjadTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (letter, value) in ABJAD_LETTERS.into_iter().zip(self.values) {
+            writeln!(f, "\"{letter}\" = {value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for AbjadTable {
+    type Err = AbjadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table = Self::MASHRIQI;
+
+        for (line_index, line) in s.lines().enumerate() {
+            let invalid = || AbjadError::InvalidTable {
+                line: line_index + 1,
+                entry: line.trim().to_string(),
+            };
+
+            let line = strip_comment(line)
+                .trim()
+                .trim_start_matches('{')
+                .trim_end_matches('}');
+
+            if line.starts_with('[') {
+                continue;
+            }
+
+            for entry in line.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                let (letter, value) = entry.split_once(['=', ':']).ok_or_else(invalid)?;
+
+                let letter = letter.trim().trim_matches(['"', '\'']);
+                let mut chars = letter.chars();
+                let (Some(letter), None) = (chars.next(), chars.next()) else {
+                    return Err(invalid());
+                };
+
+                let value: u32 = value
+                    .trim()
+                    .replace('_', "")
+                    .parse()
+                    .map_err(|_| invalid())?;
+                table = table.with_value(letter, value).ok_or_else(invalid)?;
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+// A line without any comment (from a # outside quotes to the end)
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+
+    for (index, character) in line.char_indices() {
+        match (quote, character) {
+            (None, '"' | '\'') => quote = Some(character),
+            (Some(open), _) if character == open => quote = None,
+            (None, '#') => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+// The position in ABJAD_LETTERS of a letter, or of the letter from which it derives
+pub const fn letter_index(character: char) -> Option<usize> {
+    let index = match character {
+        'ا' | 'أ' | 'إ' | 'ٱ' => 0,
+        'ب' | 'پ' => 1,
+        'ج' | 'چ' => 2,
+        'د' => 3,
+        'ه' | 'ة' | 'ۀ' => 4,
+        'و' | 'ؤ' => 5,
+        'ز' | 'ژ' => 6,
+        'ح' => 7,
+        'ط' => 8,
+        'ي' | 'ى' | 'ئ' | 'ی' => 9,
+        'ك' | 'ک' | 'گ' => 10,
+        'ل' => 11,
+        'م' => 12,
+        'ن' => 13,
+        'س' => 14,
+        'ع' => 15,
+        'ف' => 16,
+        'ص' => 17,
+        'ق' => 18,
+        'ر' => 19,
+        'ش' => 20,
+        'ت' => 21,
+        'ث' => 22,
+        'خ' => 23,
+        'ذ' => 24,
+        'ض' => 25,
+        'ظ' => 26,
+        'غ' => 27,
+        _ => return None,
+    };
+
+    Some(index)
+}
@@ -0,0 +1,601 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{AbjadError, UnrecognizedChar};
+
+/// This `enum` allows for a selection of the scheme of romanization read by
+/// `transliterate`. The schemes share most letters (with diacritics such as ḥ ṣ
+/// ḍ ṭ ẓ, and ʿ and ʾ for _ʿayn_ and _hamzah_); they differ as follows.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Romanization {
+    #[default]
+    /// IJMES (default): th, kh, dh, sh, and gh are digraphs, as are ch and zh for
+    /// Persian. Since they cannot be told apart from two letters (e.g., س and ه),
+    /// each is reported as an ambiguity. Final ā may be ا or ى, and final -a is
+    /// read as ة.
+    Ijmes,
+    /// ALA-LC: as IJMES, but two letters that would otherwise be read as a digraph
+    /// are separated by a prime (ʹ), so digraphs are not ambiguous; ى is written
+    /// á; and ة is written -ah (or -at).
+    AlaLc,
+    /// DMG (Deutsche Morgenländische Gesellschaft): there are no digraphs, but
+    /// single letters ṯ ǧ ḫ ḏ š ġ (and č ž for Persian). Final ā may be ا or ى,
+    /// and final -a is read as ة.
+    Dmg,
+}
+
+impl fmt::Display for Romanization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ijmes => f.write_str("ijmes"),
+            Self::AlaLc => f.write_str("ala-lc"),
+            Self::Dmg => f.write_str("dmg"),
+        }
+    }
+}
+
+impl FromStr for Romanization {
+    type Err = AbjadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "ijmes" => Ok(Self::Ijmes),
+            "ala-lc" | "alalc" => Ok(Self::AlaLc),
+            "dmg" => Ok(Self::Dmg),
+            _ => Err(AbjadError::UnknownName(s.to_string())),
+        }
+    }
+}
+
+/// A romanized sequence that could be written in Arabic script in more than one
+/// way, reported by `transliterate`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ambiguity {
+    /// The sequence in the romanized input
+    pub latin: String,
+
+    /// The byte offset of the sequence in the romanized input
+    pub byte_offset: usize,
+
+    /// The reading chosen, in Arabic script
+    pub chosen: String,
+
+    /// The other possible readings, in Arabic script (an empty string meaning
+    /// that nothing is written)
+    pub alternatives: Vec<String>,
+}
+
+/// The result of `transliterate`: the text in Arabic script, which can be
+/// valued with any of the methods of `Abjad`, and the ambiguities found.
+/// (Positions in the results of those methods refer to `arabic`.)
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transliteration {
+    /// The text in Arabic script
+    pub arabic: String,
+
+    /// Sequences that could have been read otherwise, in order
+    pub ambiguities: Vec<Ambiguity>,
+}
+
+/// This converts romanized text (e.g., "Bahāʾ al-Dīn") into Arabic script
+/// (بهاء الدين), so that it can be valued.
+///
+/// Only what is written in Arabic script is kept: short vowels are dropped, long
+/// vowels are written with ا, ي, or و, and a vowel at the start of a word with
+/// alif. A doubled consonant is written once, with _shaddah_ (which counts only
+/// if `count_shaddah` is set). The article is written ال whether or not it is
+/// assimilated (al-Dīn, ad-Dīn, ud-Dīn), and a hyphen otherwise joins a prefix
+/// (wa-, bi-) to its word. Letters may be written with combining diacritics, and
+/// in either case. Anything other than letters, such as whitespace and
+/// punctuation, is kept as it is.
+///
+/// Romanization does not always determine the spelling, so the choices made
+/// are reported, with their alternatives: the seat of a medial _hamzah_; medial ā
+/// as ا or nothing (as in الرحمن); final ā as ا or ى; final -a as ة or nothing,
+/// -ah (except in ALA-LC) as ة or ه, and -at as ت or ة; and, in IJMES, each
+/// digraph. The name of God is written الله, also when joined to a word
+/// (ʿAbdullāh, billāh), and لله in lillāh, with or without a case ending
+/// (billāhi).
+///
+/// # Errors
+/// This returns `AbjadError::UnrecognizedCharacter` for a letter that is not
+/// part of the scheme (such as x), with its position in the input.
+pub fn transliterate(
+    text: &str,
+    romanization: Romanization,
+) -> Result<Transliteration, AbjadError> {
+    let mut transliterator = Transliterator {
+        text,
+        romanization,
+        output: Transliteration::default(),
+    };
+
+    let items = tokenize(text)?;
+    let mut index = 0;
+
+    while index < items.len() {
+        match &items[index] {
+            Item::Segment(segment) => {
+                let next = match items.get(index + 1..index + 3) {
+                    Some([Item::Hyphen, Item::Segment(next)]) => Some(next.as_slice()),
+                    _ => None,
+                };
+
+                let after_hyphen = index > 0 && items[index - 1] == Item::Hyphen;
+                transliterator.segment(segment, next, after_hyphen)?;
+            }
+            Item::Hyphen => {}
+            Item::Other(character) => transliterator.output.arabic.push(*character),
+        }
+
+        index += 1;
+    }
+
+    Ok(transliterator.output)
+}
+
+// A letter of romanized input, lowercased and with any combining marks composed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Letter {
+    character: char,
+    start: usize,
+    end: usize,
+}
+
+// Input is split into runs of letters, hyphens between them, and anything else
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Item {
+    Segment(Vec<Letter>),
+    Hyphen,
+    Other(char),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Item>, AbjadError> {
+    let mut items: Vec<Item> = Vec::new();
+    let mut chars = text.char_indices().enumerate().peekable();
+
+    while let Some((char_index, (byte_offset, character))) = chars.next() {
+        let unrecognized = || {
+            AbjadError::UnrecognizedCharacter(UnrecognizedChar {
+                character,
+                byte_offset,
+                char_index,
+            })
+        };
+
+        let end = byte_offset + character.len_utf8();
+
+        if is_combining(character) {
+            // Only a mark following a letter is composed with it
+            let Some(Item::Segment(segment)) = items.last_mut() else {
+                return Err(unrecognized());
+            };
+
+            let last = segment.last_mut().expect("segments are not empty");
+            last.character = unicode_normalization::char::compose(last.character, character)
+                .ok_or_else(unrecognized)?;
+            last.end = end;
+        } else if is_roman(character) {
+            let letter = Letter {
+                character: character.to_lowercase().next().unwrap_or(character),
+                start: byte_offset,
+                end,
+            };
+
+            match items.last_mut() {
+                Some(Item::Segment(segment)) => segment.push(letter),
+                _ => items.push(Item::Segment(vec![letter])),
+            }
+        } else if character == '-'
+            && matches!(items.last(), Some(Item::Segment(_)))
+            && chars.peek().is_some_and(|&(_, (_, next))| is_roman(next))
+        {
+            items.push(Item::Hyphen);
+        } else {
+            items.push(Item::Other(character));
+        }
+    }
+
+    Ok(items)
+}
+
+struct Transliterator<'a> {
+    text: &'a str,
+    romanization: Romanization,
+    output: Transliteration,
+}
+
+impl Transliterator<'_> {
+    // A run of letters, which may be followed by a hyphen and another run
+    fn segment(
+        &mut self,
+        segment: &[Letter],
+        next: Option<&[Letter]>,
+        after_hyphen: bool,
+    ) -> Result<(), AbjadError> {
+        if next.is_some_and(|next| self.is_article(segment, next)) {
+            self.output.arabic.push_str("ال");
+            return Ok(());
+        }
+
+        // The Persian izafat (e.g., Dīvān-i Shams) is not written
+        if after_hyphen && segment.iter().all(|l| is_short_vowel(l.character)) {
+            return Ok(());
+        }
+
+        let mut last_consonant: Option<char> = None;
+        let mut index = 0;
+
+        while index < segment.len() {
+            let rest: String = segment[index..].iter().map(|l| l.character).collect();
+
+            // A case ending (as in billāhi) is not written
+            let name = match rest.strip_suffix(['a', 'i', 'u']) {
+                Some(stem) if stem.ends_with('h') => stem,
+                _ => rest.as_str(),
+            };
+
+            // The name of God keeps the alif of the article, which takes the place
+            // of a connecting vowel (ʿAbdullāh, billāh), except after li- (lillāh)
+            let after_consonant = index == 0 || !is_vowel(segment[index - 1].character);
+
+            match name {
+                "lillāh" | "lillah" if index == 0 => self.output.arabic.push_str("لله"),
+                "allāh" | "allah" | "ullāh" | "ullah" | "illāh" | "illah" if after_consonant => {
+                    self.output.arabic.push_str("الله");
+                }
+                "llāh" | "llah" if self.output.arabic.ends_with('ل') => {
+                    self.output.arabic.push_str("له");
+                }
+                "llāh" | "llah" => self.output.arabic.push_str("الله"),
+                _ => {
+                    index += self.letter(segment, index, next.is_some(), &mut last_consonant)?;
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        Ok(())
+    }
+
+    // Handles the letter (or digraph) at the given index, returning its length
+    fn letter(
+        &mut self,
+        segment: &[Letter],
+        index: usize,
+        before_hyphen: bool,
+        last_consonant: &mut Option<char>,
+    ) -> Result<usize, AbjadError> {
+        let character = segment[index].character;
+
+        if let Some((consonant, length)) = self.consonant(segment, index) {
+            self.push_consonant(
+                segment,
+                index,
+                length,
+                consonant,
+                before_hyphen,
+                last_consonant,
+            );
+            return Ok(length);
+        }
+
+        if is_hamzah(character) {
+            *last_consonant = None;
+            return Ok(self.hamzah(segment, index));
+        }
+
+        let is_final = index + 1 == segment.len();
+
+        let written = match character {
+            // A prime separates two letters that would otherwise be a digraph
+            'ʹ' | '′' => "",
+            _ if is_short_vowel(character) => {
+                if index == 0 {
+                    "ا"
+                } else if is_final
+                    && character == 'a'
+                    && !before_hyphen
+                    && self.is_ta_marbutah(segment)
+                {
+                    self.ambiguity(segment, index, index + 1, "ة", &[""]);
+                    "ة"
+                } else {
+                    ""
+                }
+            }
+            'ā' | 'â' if index == 0 => "آ",
+            'ā' | 'â' => {
+                let before_hamzah = segment
+                    .get(index + 1)
+                    .is_some_and(|l| is_hamzah(l.character));
+
+                // A medial ā may be left unwritten, as in al-Raḥmān (الرحمن)
+                if !is_final && !before_hamzah {
+                    self.ambiguity(segment, index, index + 1, "ا", &[""]);
+                } else if is_final && self.romanization != Romanization::AlaLc {
+                    self.ambiguity(segment, index, index + 1, "ا", &["ى"]);
+                }
+
+                "ا"
+            }
+            'á' => "ى",
+            'ī' | 'î' | 'ē' if index == 0 => "اي",
+            'ī' | 'î' | 'ē' => "ي",
+            'ū' | 'û' | 'ō' if index == 0 => "او",
+            'ū' | 'û' | 'ō' => "و",
+            _ => return Err(self.unrecognized(&segment[index])),
+        };
+
+        // A long vowel may be followed by the same letter, doubled, as in -īya
+        *last_consonant = match written {
+            "ي" | "اي" => Some('ي'),
+            "و" | "او" => Some('و'),
+            _ => None,
+        };
+
+        self.output.arabic.push_str(written);
+        Ok(1)
+    }
+
+    fn push_consonant(
+        &mut self,
+        segment: &[Letter],
+        index: usize,
+        length: usize,
+        consonant: char,
+        before_hyphen: bool,
+        last_consonant: &mut Option<char>,
+    ) {
+        let is_final = index + length == segment.len();
+        let after_short_a = index >= 2 && segment[index - 1].character == 'a';
+
+        // Final -ah is ta' marbutah in ALA-LC, and may be elsewhere; -at may be
+        // too, in construct
+        if is_final && after_short_a && consonant == 'ه' {
+            if self.romanization != Romanization::AlaLc {
+                self.ambiguity(segment, index - 1, index + 1, "ة", &["ه"]);
+            }
+
+            self.output.arabic.push('ة');
+            return;
+        }
+
+        if is_final && after_short_a && consonant == 'ت' && !before_hyphen {
+            self.ambiguity(segment, index - 1, index + 1, "ت", &["ة"]);
+        }
+
+        let doubled = *last_consonant == Some(consonant);
+
+        if length == 2 && !doubled && self.romanization == Romanization::Ijmes {
+            if let Some(first) = single_consonant(segment[index].character) {
+                let alternative = format!("{first}ه");
+                self.ambiguity(
+                    segment,
+                    index,
+                    index + 2,
+                    &consonant.to_string(),
+                    &[&alternative],
+                );
+            }
+        }
+
+        // A doubled consonant is written once, with shaddah
+        if doubled {
+            self.output.arabic.push('\u{0651}');
+            *last_consonant = None;
+        } else {
+            self.output.arabic.push(consonant);
+            *last_consonant = Some(consonant);
+        }
+    }
+
+    // Writes hamzah with its seat, returning the number of letters used
+    fn hamzah(&mut self, segment: &[Letter], index: usize) -> usize {
+        let previous = index.checked_sub(1).map(|i| segment[i].character);
+        let next = segment.get(index + 1).map(|l| l.character);
+
+        // Hamzah followed by ā is written with maddah, unless it follows a vowel
+        if matches!(next, Some('ā' | 'â')) && !previous.is_some_and(is_vowel) {
+            self.output.arabic.push('آ');
+            return 2;
+        }
+
+        if index == 0 {
+            self.output.arabic.push('ا');
+            return 1;
+        }
+
+        // Final hamzah after ā, as in Bahāʾ, is written on its own
+        if next.is_none() && matches!(previous, Some('ā' | 'â')) {
+            self.output.arabic.push('ء');
+            return 1;
+        }
+
+        let neighbours = [previous, next];
+        let has = |vowels: &[char]| neighbours.iter().flatten().any(|c| vowels.contains(c));
+
+        let seat = if has(&['i', 'ī', 'î', 'e', 'ē']) {
+            "ئ"
+        } else if has(&['u', 'ū', 'û', 'o', 'ō']) {
+            "ؤ"
+        } else if has(&['a', 'ā', 'â']) {
+            "أ"
+        } else {
+            "ء"
+        };
+
+        let alternatives: Vec<&str> = ["أ", "ؤ", "ئ", "ء"]
+            .into_iter()
+            .filter(|&alternative| alternative != seat)
+            .collect();
+
+        self.ambiguity(segment, index, index + 1, seat, &alternatives);
+        self.output.arabic.push_str(seat);
+        1
+    }
+
+    // The consonant (or digraph) at the given index, and its length
+    fn consonant(&self, segment: &[Letter], index: usize) -> Option<(char, usize)> {
+        let character = segment[index].character;
+        let before_h = segment.get(index + 1).is_some_and(|l| l.character == 'h');
+
+        if before_h && self.romanization != Romanization::Dmg {
+            if let Some(digraph) = digraph(character) {
+                return Some((digraph, 2));
+            }
+        }
+
+        single_consonant(character).map(|consonant| (consonant, 1))
+    }
+
+    // The article, al- (or el-, ul-, l-), or assimilated to a sun letter (ad-)
+    fn is_article(&self, segment: &[Letter], next: &[Letter]) -> bool {
+        let is_vowel_at = |i: usize| segment.get(i).is_some_and(|l| is_short_vowel(l.character));
+
+        let consonant_index = usize::from(is_vowel_at(0));
+
+        let Some((consonant, length)) = self.consonant(segment, consonant_index) else {
+            return false;
+        };
+
+        if consonant_index + length != segment.len() {
+            return false;
+        }
+
+        consonant == 'ل'
+            || consonant_index == 1
+                && SUN_LETTERS.contains(&consonant)
+                && self
+                    .consonant(next, 0)
+                    .is_some_and(|(first, _)| first == consonant)
+    }
+
+    // Final -a is read as ta' marbutah after at least two consonants (but ALA-LC
+    // writes it -ah)
+    fn is_ta_marbutah(&self, segment: &[Letter]) -> bool {
+        let consonants = segment
+            .iter()
+            .filter(|l| single_consonant(l.character).is_some() || is_hamzah(l.character))
+            .count();
+
+        self.romanization != Romanization::AlaLc
+            && consonants >= 2
+            && segment.len() >= 2
+            && !is_vowel(segment[segment.len() - 2].character)
+    }
+
+    fn ambiguity(
+        &mut self,
+        segment: &[Letter],
+        from: usize,
+        to: usize,
+        chosen: &str,
+        alternatives: &[&str],
+    ) {
+        self.output.ambiguities.push(Ambiguity {
+            latin: self.text[segment[from].start..segment[to - 1].end].to_string(),
+            byte_offset: segment[from].start,
+            chosen: chosen.to_string(),
+            alternatives: alternatives.iter().map(ToString::to_string).collect(),
+        });
+    }
+
+    fn unrecognized(&self, letter: &Letter) -> AbjadError {
+        AbjadError::UnrecognizedCharacter(UnrecognizedChar {
+            character: self.text[letter.start..].chars().next().unwrap_or_default(),
+            byte_offset: letter.start,
+            char_index: self.text[..letter.start].chars().count(),
+        })
+    }
+}
+
+const SUN_LETTERS: [char; 14] = [
+    'ت', 'ث', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', 'ض', 'ط', 'ظ', 'ل', 'ن',
+];
+
+// Consonants written with a single letter, in any of the schemes
+const fn single_consonant(character: char) -> Option<char> {
+    let consonant = match character {
+        'b' => 'ب',
+        'p' => 'پ',
+        't' => 'ت',
+        'ṯ' => 'ث',
+        'j' | 'ǧ' => 'ج',
+        'č' => 'چ',
+        'ḥ' => 'ح',
+        'ḫ' => 'خ',
+        'd' => 'د',
+        'ḏ' => 'ذ',
+        'r' => 'ر',
+        'z' => 'ز',
+        'ž' => 'ژ',
+        's' => 'س',
+        'š' => 'ش',
+        'ṣ' => 'ص',
+        'ḍ' => 'ض',
+        'ṭ' => 'ط',
+        'ẓ' => 'ظ',
+        'ʿ' | '‘' | '`' => 'ع',
+        'ġ' => 'غ',
+        'f' => 'ف',
+        'q' => 'ق',
+        'k' => 'ك',
+        'g' => 'گ',
+        'l' => 'ل',
+        'm' => 'م',
+        'n' => 'ن',
+        'h' => 'ه',
+        'w' | 'v' => 'و',
+        'y' => 'ي',
+        _ => return None,
+    };
+
+    Some(consonant)
+}
+
+// The first letters of the digraphs with h, in IJMES and ALA-LC
+const fn digraph(character: char) -> Option<char> {
+    let consonant = match character {
+        't' => 'ث',
+        'k' => 'خ',
+        'd' => 'ذ',
+        's' => 'ش',
+        'g' => 'غ',
+        'c' => 'چ',
+        'z' => 'ژ',
+        _ => return None,
+    };
+
+    Some(consonant)
+}
+
+const fn is_short_vowel(character: char) -> bool {
+    matches!(character, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+const fn is_vowel(character: char) -> bool {
+    is_short_vowel(character)
+        || matches!(
+            character,
+            'ā' | 'â' | 'á' | 'ī' | 'î' | 'ē' | 'ū' | 'û' | 'ō'
+        )
+}
+
+const fn is_hamzah(character: char) -> bool {
+    matches!(character, 'ʾ' | 'ʼ' | '’' | '\'')
+}
+
+fn is_roman(character: char) -> bool {
+    character.is_alphabetic() || is_hamzah(character) || matches!(character, '‘' | '`' | '′')
+}
+
+const fn is_combining(character: char) -> bool {
+    matches!(character, '\u{0300}'..='\u{036F}')
+}
@@ -0,0 +1,102 @@
+use crate::{Abjad, AbjadPrefs, UnrecognizedChar};
+
+/// A word found by `AbjadWords`, with its position and value.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Word<'a> {
+    /// The text of the word
+    pub text: &'a str,
+
+    /// The byte offset of the start of the word in the input
+    pub start: usize,
+
+    /// The byte offset of the end of the word in the input (exclusive)
+    pub end: usize,
+
+    /// The _abjad_ value of the word, ignoring unrecognized characters
+    pub value: u32,
+
+    /// Any unrecognized characters in the word, with their positions in the input
+    /// (not in the word)
+    pub errors: Vec<UnrecognizedChar>,
+}
+
+/// This splits a text into words, yielding each with its position and _abjad_
+/// value. Words are separated by whitespace. By default, zero-width non-joiners
+/// do not separate words, so that a Persian compound such as می‌کنیم is kept
+/// whole; use `split_on_zwnj` to change this.
+///
+/// Each word is valued as by `abjad_collect_errors`. Since a _shaddah_ never
+/// doubles a letter across a space, the values of the words always add up to
+/// the value of the text.
+#[derive(Clone, Debug)]
+pub struct AbjadWords<'a> {
+    text: &'a str,
+    prefs: AbjadPrefs,
+    split_on_zwnj: bool,
+    byte_offset: usize,
+    char_index: usize,
+}
+
+impl<'a> AbjadWords<'a> {
+    /// This prepares to split `text` into words, valued according to `prefs`.
+    #[must_use]
+    pub const fn new(text: &'a str, prefs: AbjadPrefs) -> Self {
+        Self {
+            text,
+            prefs,
+            split_on_zwnj: false,
+            byte_offset: 0,
+            char_index: 0,
+        }
+    }
+
+    /// Should zero-width non-joiners separate words? This is false by default.
+    #[must_use]
+    pub const fn split_on_zwnj(mut self, split: bool) -> Self {
+        self.split_on_zwnj = split;
+        self
+    }
+
+    const fn is_separator(&self, character: char) -> bool {
+        character.is_whitespace() || (self.split_on_zwnj && character == '\u{200C}')
+    }
+}
+
+impl<'a> Iterator for AbjadWords<'a> {
+    type Item = Word<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start: Option<(usize, usize)> = None;
+
+        for character in self.text[self.byte_offset..].chars() {
+            if self.is_separator(character) {
+                if start.is_some() {
+                    break;
+                }
+            } else if start.is_none() {
+                start = Some((self.byte_offset, self.char_index));
+            }
+
+            self.byte_offset += character.len_utf8();
+            self.char_index += 1;
+        }
+
+        let (start, start_char) = start?;
+        let text = &self.text[start..self.byte_offset];
+        let (value, mut errors) = text.abjad_collect_errors(self.prefs);
+
+        for error in &mut errors {
+            error.byte_offset += start;
+            error.char_index += start_char;
+        }
+
+        Some(Word {
+            text,
+            start,
+            end: self.byte_offset,
+            value,
+            errors,
+        })
+    }
+}
@@ -1,7 +1,12 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
 
-use abjad::{Abjad, AbjadPrefs, LetterOrder};
+use std::borrow::Cow;
+
+use abjad::{
+    Abjad, AbjadError, AbjadPrefs, CalculationMode, Chars, GeneralCategory, LetterOrder,
+    LetterRule, NameSpelling, Orthography, Script,
+};
 
 #[test]
 fn all() {
@@ -135,3 +140,469 @@ fn zwnj() {
 
     assert_eq!(input.abjad_strict(prefs).unwrap(), 645);
 }
+
+#[test]
+fn breakdown() {
+    let input = "قد تمّمته";
+    let prefs = AbjadPrefs {
+        count_shaddah: true,
+        ..AbjadPrefs::default()
+    };
+
+    let entries = input.abjad_breakdown(prefs);
+
+    assert_eq!(entries.len(), 9);
+    assert_eq!(entries[2].rule, LetterRule::Separator);
+    assert_eq!(entries[5].rule, LetterRule::Shaddah);
+    assert_eq!(entries[5].value, 40);
+    assert_eq!(entries[5].byte_offset, 9);
+    assert_eq!(entries[5].char_index, 5);
+    assert_eq!(entries[8].running_total, 1_029);
+    assert_eq!(entries[8].running_total, input.abjad_strict(prefs).unwrap());
+}
+
+#[test]
+fn breakdown_maghribi() {
+    let input = "شمس";
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Maghribi,
+        ..AbjadPrefs::default()
+    };
+
+    let entries = input.abjad_breakdown(prefs);
+    let rules: Vec<LetterRule> = entries.iter().map(|e| e.rule).collect();
+
+    assert_eq!(
+        rules,
+        [
+            LetterRule::Maghribi,
+            LetterRule::Letter,
+            LetterRule::Maghribi
+        ]
+    );
+    assert_eq!(entries[2].running_total, 1_340);
+}
+
+#[test]
+fn breakdown_unrecognized() {
+    let input = "آب x";
+    let prefs = AbjadPrefs {
+        double_alif_maddah: true,
+        ..AbjadPrefs::default()
+    };
+
+    let entries = input.abjad_breakdown(prefs);
+
+    assert_eq!(entries[0].rule, LetterRule::AlifMaddah);
+    assert_eq!(entries[0].value, 2);
+    assert_eq!(entries[3].rule, LetterRule::Unrecognized);
+    assert_eq!(entries[3].value, 0);
+    assert_eq!(entries[3].running_total, 4);
+}
+
+#[test]
+fn error_position() {
+    let input = "روح الله tapdancing خمینی";
+    let prefs = AbjadPrefs::default();
+
+    let Err(AbjadError::UnrecognizedCharacter(error)) = input.abjad_strict(prefs) else {
+        panic!("expected an unrecognized character");
+    };
+
+    assert_eq!(error.character, 't');
+    assert_eq!(error.byte_offset, 16);
+    assert_eq!(error.char_index, 9);
+    assert_eq!(error.general_category(), GeneralCategory::LowercaseLetter);
+    assert_eq!(error.script(), Script::Latin);
+    assert_eq!(error.to_string(), "\\u{74} at byte 16 (char 9)");
+}
+
+#[test]
+fn error_report_positions() {
+    let input = "بسم، الله";
+    let prefs = AbjadPrefs::default();
+
+    let (total, errors) = input.abjad_collect_errors(prefs);
+
+    assert_eq!(total, 168);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].character, '،');
+    assert_eq!(errors[0].byte_offset, 6);
+    assert_eq!(errors[0].char_index, 3);
+    assert_eq!(errors[0].script(), Script::Common);
+}
+
+#[test]
+fn overflow() {
+    // 4,294,968 instances of ghayn come to just over u32::MAX
+    let input = "غ".repeat(4_294_968);
+    let prefs = AbjadPrefs::default();
+
+    assert_eq!(input.as_str().abjad(prefs), u32::MAX);
+    assert_eq!(input.as_str().abjad_checked(prefs), None);
+    assert_eq!(input.as_str().abjad_wide(prefs), 4_294_968_000);
+
+    let Err(AbjadError::Overflow { char_index, .. }) = input.as_str().abjad_strict(prefs) else {
+        panic!("expected an overflow");
+    };
+
+    assert_eq!(char_index, 4_294_967);
+}
+
+#[test]
+fn checked() {
+    let input = "بسم الله الرحمن الرحيم";
+    let prefs = AbjadPrefs::default();
+
+    assert_eq!(input.abjad_checked(prefs), Some(786));
+    assert_eq!(input.abjad_wide(prefs), 786);
+}
+
+#[test]
+fn urdu() {
+    let input = "ہے ٹھنڈی ہوا";
+    let prefs = AbjadPrefs {
+        orthography: Orthography::Urdu,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 496);
+}
+
+#[test]
+fn urdu_fail() {
+    let input = "ہے ٹھنڈی ہوا";
+    let prefs = AbjadPrefs::default();
+
+    let (total, errors) = input.abjad_collect_errors(prefs);
+
+    assert_eq!(total, 67);
+    assert_eq!(errors.len(), 6);
+}
+
+#[test]
+fn ottoman() {
+    let input = "دڭز ۋه";
+    let prefs = AbjadPrefs {
+        orthography: Orthography::Ottoman,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 42);
+}
+
+#[test]
+fn ottoman_fail() {
+    let input = "دڭز";
+    let prefs = AbjadPrefs {
+        orthography: Orthography::Urdu,
+        ..AbjadPrefs::default()
+    };
+
+    assert!(input.abjad_strict(prefs).is_err());
+}
+
+#[test]
+fn presentation_forms() {
+    let input = "ﺑﺴﻢ ﷲ ﻻ";
+    let prefs = AbjadPrefs {
+        fold_presentation_forms: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 199);
+
+    let entries = input.abjad_breakdown(prefs);
+
+    assert_eq!(entries[4].rule, LetterRule::PresentationForm);
+    assert_eq!(entries[4].value, 66);
+}
+
+#[test]
+fn presentation_forms_persian() {
+    let input = "ﭘﺎﺩﺷﺎﻩ ﷼";
+    let prefs = AbjadPrefs {
+        fold_presentation_forms: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 554);
+}
+
+#[test]
+fn presentation_forms_fail() {
+    let input = "ﺑﺴﻢ ﷲ ﻻ";
+    let prefs = AbjadPrefs::default();
+
+    let (total, errors) = input.abjad_collect_errors(prefs);
+
+    assert_eq!(total, 0);
+    assert_eq!(errors.len(), 5);
+}
+
+#[test]
+fn diacritics() {
+    let input = "مُحَمَّدٌ";
+    let prefs = AbjadPrefs {
+        count_shaddah: true,
+        ignore_diacritics: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 132);
+}
+
+#[test]
+fn diacritics_fail() {
+    let input = "مُحَمَّدٌ";
+    let prefs = AbjadPrefs::default();
+
+    let (total, errors) = input.abjad_collect_errors(prefs);
+
+    assert_eq!(total, 92);
+    assert_eq!(errors.len(), 4);
+}
+
+#[test]
+fn superscript_alif() {
+    let input = "الرَّحْمٰنِ";
+    let prefs_ignore = AbjadPrefs {
+        ignore_diacritics: true,
+        ..AbjadPrefs::default()
+    };
+    let prefs_count = AbjadPrefs {
+        count_superscript_alif: true,
+        ..prefs_ignore
+    };
+
+    assert_eq!(input.abjad_strict(prefs_ignore).unwrap(), 329);
+    assert_eq!(input.abjad_strict(prefs_count).unwrap(), 330);
+}
+
+#[test]
+fn quranic_marks() {
+    let input = "ذٰلِكَ ٱلْكِتَٰبُ لَا رَيْبَۛ فِيهِۛ";
+    let prefs = AbjadPrefs {
+        ignore_diacritics: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 1_541);
+}
+
+#[test]
+fn poem() {
+    let input = "بشنو این نی چون شکایت می‌کند،\nاز جدایی‌ها حکایت می‌کند؛";
+    let prefs = AbjadPrefs {
+        ignore_whitespace: true,
+        ignore_punctuation: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert!(input.abjad_strict(AbjadPrefs::default()).is_err());
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 1_998);
+}
+
+#[test]
+fn kashida_digits() {
+    let input = "سـلام ۱۲ ٣";
+    let prefs = AbjadPrefs {
+        ignore_kashida: true,
+        ignore_digits: true,
+        ..AbjadPrefs::default()
+    };
+
+    let entries = input.abjad_breakdown(prefs);
+
+    assert_eq!(entries[1].rule, LetterRule::Ignored);
+    assert_eq!(entries[9].rule, LetterRule::Ignored);
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 131);
+}
+
+#[test]
+fn joiners() {
+    let input = "\u{200F}لا\u{200D}";
+    let prefs = AbjadPrefs {
+        ignore_joiners: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert!(input.abjad_strict(AbjadPrefs::default()).is_err());
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 31);
+}
+
+#[test]
+fn saghir() {
+    let input = "بسم الله الرحمن الرحيم";
+    let prefs = AbjadPrefs {
+        mode: CalculationMode::Saghir,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 102);
+}
+
+#[test]
+fn saghir_shaddah() {
+    let input = "قد تمّمته";
+    let prefs = AbjadPrefs {
+        count_shaddah: true,
+        mode: CalculationMode::Saghir,
+        ..AbjadPrefs::default()
+    };
+
+    let entries = input.abjad_breakdown(prefs);
+
+    assert_eq!(entries[4].value, 4);
+    assert_eq!(entries[5].value, 4);
+    assert_eq!(entries[8].running_total, 33);
+}
+
+#[test]
+fn malfuzi() {
+    let input = "بسم الله";
+    let prefs = AbjadPrefs {
+        mode: CalculationMode::Malfuzi(NameSpelling::Plain),
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 472);
+}
+
+#[test]
+fn malfuzi_hamzated() {
+    let input = "بها";
+    let prefs_plain = AbjadPrefs {
+        mode: CalculationMode::Malfuzi(NameSpelling::Plain),
+        ..AbjadPrefs::default()
+    };
+    let prefs_hamzated = AbjadPrefs {
+        mode: CalculationMode::Malfuzi(NameSpelling::Hamzated),
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs_plain).unwrap(), 120);
+    assert_eq!(input.abjad_strict(prefs_hamzated).unwrap(), 122);
+    assert_eq!(NameSpelling::Hamzated.name('ز'), Some("زاء"));
+    assert_eq!(NameSpelling::Plain.name('پ'), None);
+}
+
+#[test]
+fn malfuzi_maghribi() {
+    let input = "س";
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Maghribi,
+        mode: CalculationMode::Malfuzi(NameSpelling::Plain),
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 360);
+}
+
+#[test]
+fn owned_and_borrowed() {
+    let owned = String::from("بهاء الدین");
+    let cow: Cow<str> = Cow::Borrowed("بهاء الدین");
+
+    assert_eq!((&owned).abjad(AbjadPrefs::default()), 104);
+    assert_eq!(owned.abjad(AbjadPrefs::default()), 104);
+    assert_eq!((&cow).abjad(AbjadPrefs::default()), 104);
+    assert_eq!(cow.abjad(AbjadPrefs::default()), 104);
+}
+
+#[test]
+fn single_char() {
+    let prefs = AbjadPrefs {
+        count_shaddah: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!('غ'.abjad(prefs), 1000);
+    assert_eq!('\u{0651}'.abjad(prefs), 0);
+
+    let Err(AbjadError::UnrecognizedCharacter(error)) = 'x'.abjad_strict(prefs) else {
+        panic!("expected an unrecognized character");
+    };
+
+    assert_eq!(error.character, 'x');
+}
+
+#[test]
+fn char_iterator() {
+    let input = "محمّد tعلی";
+    let prefs = AbjadPrefs {
+        count_shaddah: true,
+        ..AbjadPrefs::default()
+    };
+
+    let chars: Vec<char> = input.chars().collect();
+
+    assert_eq!(Chars(chars.clone()).abjad(prefs), 242);
+    assert_eq!(
+        Chars(chars.iter().copied()).abjad_collect_errors(prefs),
+        input.abjad_collect_errors(prefs)
+    );
+    assert_eq!(Chars(input.chars().rev()).abjad(prefs), 206);
+    assert_eq!(
+        Chars(input.chars()).abjad_breakdown(prefs),
+        input.abjad_breakdown(prefs)
+    );
+}
+
+#[test]
+fn decomposed() {
+    let composed = "\u{0622}مد \u{0623}حمد \u{0625}لى م\u{0624}من \u{0626} خان\u{06C0}";
+    let decomposed =
+        "\u{0627}\u{0653}مد \u{0627}\u{0654}حمد \u{0627}\u{0655}لى م\u{0648}\u{0654}من \u{06CC}\u{0654} خان\u{0647}\u{0654}";
+    let prefs = AbjadPrefs {
+        double_alif_maddah: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(
+        composed.abjad_strict(prefs).unwrap(),
+        decomposed.abjad_strict(prefs).unwrap()
+    );
+    assert_eq!(
+        composed.abjad_strict(AbjadPrefs::default()).unwrap(),
+        decomposed.abjad_strict(AbjadPrefs::default()).unwrap()
+    );
+
+    let breakdown = "\u{0627}\u{0653}".abjad_breakdown(prefs);
+
+    assert_eq!(breakdown[1].rule, LetterRule::CombiningMark);
+    assert_eq!(breakdown[1].value, 1);
+    assert_eq!(breakdown[1].running_total, 2);
+
+    // In NFD, shaddah comes before maddah, having doubled the bare alif
+    let shaddah = AbjadPrefs {
+        count_shaddah: true,
+        ..prefs
+    };
+
+    assert_eq!("\u{0622}\u{0651}".abjad_strict(shaddah).unwrap(), 4);
+    assert_eq!("\u{0627}\u{0651}\u{0653}".abjad_strict(shaddah).unwrap(), 4);
+}
+
+#[test]
+fn decomposed_after_diacritic() {
+    let prefs = AbjadPrefs {
+        double_alif_maddah: true,
+        ignore_diacritics: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!("\u{0627}\u{064E}\u{0653}".abjad_strict(prefs).unwrap(), 2);
+    assert_eq!('\u{0653}'.abjad(prefs), 0);
+    assert!("\u{0628}\u{0654}"
+        .abjad_strict(AbjadPrefs::default())
+        .is_err());
+}
+
+#[test]
+fn yeh_forms() {
+    assert_eq!(
+        "\u{064A} \u{06CC} \u{0649}".abjad(AbjadPrefs::default()),
+        30
+    );
+}
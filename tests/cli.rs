@@ -0,0 +1,128 @@
+#![cfg(feature = "cli")]
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn abjad(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_abjad"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn total() {
+    let output = abjad(&["بهاء", "الدین"]);
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "104\n");
+}
+
+#[test]
+fn stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_abjad"))
+        .args(["--preset", "persian-chronogram", "--count-shaddah"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all("محمّد،\nعلی\n".as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "242\n");
+}
+
+#[test]
+fn modes() {
+    let lenient = abjad(&["بt"]);
+    let collect = abjad(&["--collect", "بt"]);
+    let strict = abjad(&["--strict", "بt"]);
+
+    assert_eq!(lenient.status.code(), Some(0));
+    assert_eq!(collect.status.code(), Some(1));
+    assert_eq!(stdout(&collect), "2\n");
+    assert_eq!(strict.status.code(), Some(1));
+    assert!(stdout(&strict).is_empty());
+}
+
+#[test]
+fn json() {
+    let output = abjad(&["--collect", "-w", "-f", "json", "بt ج"]);
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(value["total"], 5);
+    assert_eq!(value["errors"][0]["char_index"], 1);
+    assert_eq!(value["words"][1]["text"], "ج");
+    assert_eq!(value["words"][1]["value"], 3);
+}
+
+#[test]
+fn csv() {
+    let output = abjad(&["--letters", "--format=csv", "--mode", "saghir", "بش"]);
+
+    assert_eq!(
+        stdout(&output),
+        "character,byte_offset,char_index,value,rule,running_total,symbol,name\n\
+         ب,0,0,2,letter,2,b,bāʾ\n\
+         ش,2,1,12,letter,14,sh,shīn\n"
+    );
+}
+
+#[test]
+fn labels() {
+    let output = abjad(&["-l", "-f", "json", "-r", "dmg", "شّ"]);
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(value["letters"][0]["label"]["symbol"], "š");
+    assert_eq!(value["letters"][0]["label"]["name"], "šīn");
+    assert!(value["letters"][1]["label"].is_null());
+}
+
+#[test]
+fn end_of_options() {
+    let output = abjad(&["--", "-p", "ب"]);
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "2\n");
+
+    // The value of another option is not taken for -p
+    let value = abjad(&["--orthography", "-p", "ب"]);
+    let stderr = std::str::from_utf8(&value.stderr).unwrap();
+
+    assert_eq!(value.status.code(), Some(2));
+    assert!(stderr.starts_with("abjad: Unknown name: -p"));
+}
+
+#[test]
+fn invalid_arguments() {
+    assert_eq!(abjad(&["--bogus"]).status.code(), Some(2));
+    assert_eq!(abjad(&["--mode", "akbar"]).status.code(), Some(2));
+    assert_eq!(
+        abjad(&["-p", "quranic", "-p", "bogus", "ب"]).status.code(),
+        Some(2)
+    );
+    assert_eq!(
+        abjad(&["-p", "quranic", "--preset=quranic", "ب"])
+            .status
+            .code(),
+        Some(2)
+    );
+    assert_eq!(
+        abjad(&["-w", "-l", "-f", "csv", "ب"]).status.code(),
+        Some(2)
+    );
+}
@@ -0,0 +1,180 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{
+    parse_abjad_numeral, to_abjad_numeral, Abjad, AbjadError, AbjadPrefs, AbjadTable,
+    ComponentOrder, LetterOrder, NumeralFault, NumeralPrefs, ThousandsStyle,
+};
+
+#[test]
+fn numeral() {
+    let prefs = NumeralPrefs::default();
+
+    assert_eq!(to_abjad_numeral(1_295, prefs).unwrap(), "غرصه");
+    assert_eq!(to_abjad_numeral(66, prefs).unwrap(), "سو");
+    assert_eq!(to_abjad_numeral(0, prefs), None);
+}
+
+#[test]
+fn numeral_ascending() {
+    let prefs = NumeralPrefs {
+        component_order: ComponentOrder::Ascending,
+        ..NumeralPrefs::default()
+    };
+
+    assert_eq!(to_abjad_numeral(1_295, prefs).unwrap(), "هصرغ");
+    assert_eq!(to_abjad_numeral(1_500, prefs).unwrap(), "ثغ");
+    assert_eq!(to_abjad_numeral(2_000, prefs).unwrap(), "غب");
+    assert_eq!(to_abjad_numeral(12_500, prefs).unwrap(), "ثغبي");
+}
+
+#[test]
+fn numeral_maghribi() {
+    let prefs = NumeralPrefs {
+        letter_order: LetterOrder::Maghribi,
+        ..NumeralPrefs::default()
+    };
+
+    let numeral = to_abjad_numeral(1_960, prefs).unwrap();
+
+    assert_eq!(numeral, "شغص");
+    let abjad_prefs = AbjadPrefs {
+        letter_order: LetterOrder::Maghribi,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(numeral.as_str().abjad_strict(abjad_prefs).unwrap(), 1_960);
+}
+
+#[test]
+fn numeral_thousands() {
+    let prefs_multiplier = NumeralPrefs::default();
+    let prefs_repeated = NumeralPrefs {
+        thousands: ThousandsStyle::Repeated,
+        ..NumeralPrefs::default()
+    };
+
+    assert_eq!(to_abjad_numeral(3_004, prefs_multiplier).unwrap(), "جغد");
+    assert_eq!(to_abjad_numeral(3_004, prefs_repeated).unwrap(), "غغغد");
+    assert_eq!(to_abjad_numeral(12_000, prefs_multiplier).unwrap(), "يبغ");
+    assert_eq!(to_abjad_numeral(1_000_000, prefs_multiplier).unwrap(), "غغ");
+}
+
+#[test]
+fn parse() {
+    let prefs = NumeralPrefs::default();
+
+    assert_eq!(parse_abjad_numeral("غرصه", prefs).unwrap(), 1_295);
+    assert_eq!(parse_abjad_numeral("جغد", prefs).unwrap(), 3_004);
+    assert_eq!(parse_abjad_numeral("يبغ", prefs).unwrap(), 12_000);
+    assert_eq!(parse_abjad_numeral("غغ", prefs).unwrap(), 1_000_000);
+    assert_eq!(parse_abjad_numeral("ثغ", prefs).unwrap(), 500_000);
+    assert_eq!(parse_abjad_numeral("غ رصه", prefs).unwrap(), 1_295);
+}
+
+#[test]
+fn parse_ascending() {
+    let prefs = NumeralPrefs {
+        component_order: ComponentOrder::Ascending,
+        ..NumeralPrefs::default()
+    };
+
+    assert_eq!(parse_abjad_numeral("ثغ", prefs).unwrap(), 1_500);
+    assert_eq!(parse_abjad_numeral("هصرغ", prefs).unwrap(), 1_295);
+    assert_eq!(parse_abjad_numeral("ثغب", prefs).unwrap(), 2_500);
+    assert_eq!(parse_abjad_numeral("بثغ", prefs).unwrap(), 1_502);
+    assert_eq!(parse_abjad_numeral("بغ", prefs).unwrap(), 1_002);
+}
+
+#[test]
+fn parse_repeated() {
+    let prefs = NumeralPrefs {
+        thousands: ThousandsStyle::Repeated,
+        ..NumeralPrefs::default()
+    };
+
+    assert_eq!(parse_abjad_numeral("غ غ غ د", prefs).unwrap(), 3_004);
+
+    let Err(AbjadError::MalformedNumeral { fault, .. }) = parse_abjad_numeral("غبغ", prefs)
+    else {
+        panic!("expected a malformed numeral");
+    };
+
+    assert_eq!(fault, NumeralFault::MixedThousands('ب'));
+}
+
+#[test]
+fn parse_malformed() {
+    let prefs = NumeralPrefs::default();
+
+    let Err(AbjadError::MalformedNumeral {
+        fault, char_index, ..
+    }) = parse_abjad_numeral("غصر", prefs)
+    else {
+        panic!("expected a malformed numeral");
+    };
+
+    assert_eq!(fault, NumeralFault::OutOfOrder('ر'));
+    assert_eq!(char_index, 2);
+
+    let Err(AbjadError::MalformedNumeral { fault, .. }) = parse_abjad_numeral("ييب", prefs)
+    else {
+        panic!("expected a malformed numeral");
+    };
+
+    assert_eq!(fault, NumeralFault::OutOfOrder('ي'));
+
+    let Err(AbjadError::MalformedNumeral { fault, .. }) = parse_abjad_numeral("بپ", prefs) else {
+        panic!("expected a malformed numeral");
+    };
+
+    assert_eq!(fault, NumeralFault::NotANumeral('پ'));
+    assert!(parse_abjad_numeral("", prefs).is_err());
+    assert!(matches!(
+        parse_abjad_numeral("غغغغ", prefs),
+        Err(AbjadError::Overflow { .. })
+    ));
+}
+
+#[test]
+fn round_trip() {
+    let prefs = NumeralPrefs::default();
+
+    for number in [1, 19, 786, 1_295, 1_500, 9_999, 66_066, 1_234_567] {
+        let numeral = to_abjad_numeral(number, prefs).unwrap();
+        assert_eq!(parse_abjad_numeral(&numeral, prefs).unwrap(), number);
+    }
+}
+
+#[test]
+fn round_trip_all() {
+    for letter_order in [LetterOrder::Mashriqi, LetterOrder::Maghribi] {
+        for thousands in [ThousandsStyle::Multiplier, ThousandsStyle::Repeated] {
+            for component_order in [ComponentOrder::Ascending, ComponentOrder::Descending] {
+                let prefs = NumeralPrefs {
+                    letter_order,
+                    thousands,
+                    component_order,
+                };
+
+                for number in 1..=20_000 {
+                    let numeral = to_abjad_numeral(number, prefs).unwrap();
+                    assert_eq!(parse_abjad_numeral(&numeral, prefs).unwrap(), number);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn numeral_custom() {
+    let table = AbjadTable::MASHRIQI.with_value('غ', 2_000).unwrap();
+    let prefs = NumeralPrefs {
+        letter_order: LetterOrder::Custom(table),
+        ..NumeralPrefs::default()
+    };
+
+    assert_eq!(to_abjad_numeral(999, prefs).unwrap(), "ظصط");
+    assert_eq!(to_abjad_numeral(1_000, prefs), None);
+    assert!(parse_abjad_numeral("غ", prefs).is_err());
+}
@@ -0,0 +1,116 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{
+    Abjad, AbjadError, AbjadPrefs, AbjadTable, CalculationMode, LetterOrder, NameSpelling,
+    Orthography, Preset,
+};
+
+#[test]
+fn preset() {
+    let input = "بَهاءُ الدّین،\nمحمّد";
+    let prefs: AbjadPrefs = Preset::PersianChronogram.into();
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 195);
+    assert_eq!(
+        input.abjad_strict(Preset::ClassicalArabic.prefs()).unwrap(),
+        196
+    );
+}
+
+#[test]
+fn preset_names() {
+    for preset in Preset::ALL {
+        assert_eq!(preset.to_string().parse::<Preset>().unwrap(), preset);
+    }
+
+    assert_eq!(
+        "Ottoman-Tarih".parse::<Preset>().unwrap(),
+        Preset::OttomanTarih
+    );
+    assert_eq!(
+        Preset::OttomanTarih.prefs().orthography,
+        Orthography::Ottoman
+    );
+    assert!(matches!(
+        "ottoman".parse::<Preset>(),
+        Err(AbjadError::UnknownName(_))
+    ));
+}
+
+#[test]
+fn letter_order_names() {
+    assert_eq!(
+        "maghribi".parse::<LetterOrder>().unwrap(),
+        LetterOrder::Maghribi
+    );
+    assert_eq!(
+        "Mashriqi".parse::<LetterOrder>().unwrap(),
+        LetterOrder::Mashriqi
+    );
+    assert_eq!(LetterOrder::Maghribi.to_string(), "maghribi");
+    assert_eq!(
+        LetterOrder::Custom(AbjadTable::MASHRIQI).to_string(),
+        "custom"
+    );
+    assert!("custom".parse::<LetterOrder>().is_err());
+}
+
+#[test]
+fn option_names() {
+    assert_eq!("Urdu".parse::<Orthography>().unwrap(), Orthography::Urdu);
+    assert_eq!(Orthography::Ottoman.to_string(), "ottoman");
+
+    let hamzated = CalculationMode::Malfuzi(NameSpelling::Hamzated);
+
+    assert_eq!(
+        "malfuzi-hamzated".parse::<CalculationMode>().unwrap(),
+        hamzated
+    );
+    assert_eq!(hamzated.to_string(), "malfuzi-hamzated");
+    assert_eq!(CalculationMode::Ausat.to_string(), "ausat");
+    assert!("akbar".parse::<CalculationMode>().is_err());
+}
+
+#[test]
+fn builder() {
+    let prefs = AbjadPrefs::builder()
+        .count_shaddah(true)
+        .letter_order(LetterOrder::Maghribi)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        prefs,
+        AbjadPrefs {
+            count_shaddah: true,
+            letter_order: LetterOrder::Maghribi,
+            ..AbjadPrefs::default()
+        }
+    );
+
+    let from_preset = Preset::Quranic
+        .builder()
+        .ignore_digits(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(from_preset, Preset::ClassicalArabic.prefs());
+}
+
+#[test]
+fn builder_invalid() {
+    let zero = AbjadTable::MASHRIQI.with_value('غ', 0).unwrap();
+    let shared = AbjadTable::MASHRIQI.with_value('غ', 900).unwrap();
+
+    let result = AbjadPrefs::builder()
+        .letter_order(LetterOrder::Custom(zero))
+        .build();
+
+    assert!(matches!(result, Err(AbjadError::InvalidPrefs(_))));
+
+    let builder = AbjadPrefs::builder().letter_order(LetterOrder::Custom(shared));
+
+    assert!(builder.build().is_ok());
+    assert!(builder.mode(CalculationMode::Ausat).build().is_err());
+}
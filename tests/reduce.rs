@@ -0,0 +1,74 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{Abjad, AbjadPrefs, CalculationMode, LetterOrder, Reduction};
+
+#[test]
+fn digital_root() {
+    assert_eq!(Reduction::DigitalRoot.reduce(786).value, 3);
+    assert_eq!(Reduction::DigitalRoot.reduce(999).value, 9);
+    assert_eq!(Reduction::DigitalRoot.reduce(0).value, 0);
+}
+
+#[test]
+fn zero() {
+    for reduction in [
+        Reduction::DigitalRoot,
+        Reduction::Mansions,
+        Reduction::Saghir,
+        Reduction::Thirty,
+    ] {
+        let reduced = reduction.reduce_text("", AbjadPrefs::default());
+
+        assert_eq!(reduced.value, 0);
+        assert_eq!(
+            (reduced.letter, reduced.mansion, reduced.sign),
+            (None, None, None)
+        );
+    }
+}
+
+#[test]
+fn mansions() {
+    let reduced = Reduction::Mansions.reduce(786);
+
+    assert_eq!(reduced.value, 2);
+    assert_eq!(reduced.letter, Some('ب'));
+    assert_eq!(reduced.mansion, Some("البطين"));
+    assert_eq!(reduced.sign, None);
+    assert_eq!(Reduction::Mansions.reduce(28).letter, Some('غ'));
+}
+
+#[test]
+fn mansions_maghribi() {
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Maghribi,
+        ..AbjadPrefs::default()
+    };
+
+    let reduced = Reduction::Mansions.reduce_text("كح", prefs);
+
+    assert_eq!(reduced.value, 28);
+    assert_eq!(reduced.letter, Some('ش'));
+}
+
+#[test]
+fn saghir() {
+    let reduced = Reduction::Saghir.reduce_text("بسم الله الرحمن الرحيم", AbjadPrefs::default());
+
+    assert_eq!(reduced.value, 6);
+    assert_eq!(reduced.sign, Some("السنبلة"));
+    assert_eq!(Reduction::Saghir.reduce(24).value, 12);
+    assert_eq!(Reduction::Thirty.reduce(786).value, 6);
+}
+
+#[test]
+fn ausat() {
+    let input = "ابجد هوز حطي كلمن سعفص قرشت ثخذ ضظغ";
+    let prefs = AbjadPrefs {
+        mode: CalculationMode::Ausat,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 406);
+}
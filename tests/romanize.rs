@@ -0,0 +1,56 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{letter_label, Abjad, AbjadPrefs, LetterLabel, Orthography, Romanization};
+
+#[test]
+fn label() {
+    assert_eq!(
+        letter_label('ص', Romanization::Ijmes),
+        Some(LetterLabel {
+            name: "ṣād",
+            symbol: "ṣ"
+        })
+    );
+    assert_eq!(letter_label('ش', Romanization::Dmg).unwrap().symbol, "š");
+    assert_eq!(letter_label('ى', Romanization::AlaLc).unwrap().symbol, "á");
+    assert_eq!(letter_label('ى', Romanization::Ijmes).unwrap().symbol, "ā");
+    assert_eq!(letter_label('\u{0651}', Romanization::Ijmes), None);
+    assert_eq!(letter_label('x', Romanization::Ijmes), None);
+}
+
+#[test]
+fn all_letters_labeled() {
+    for orthography in [
+        Orthography::Persian,
+        Orthography::Urdu,
+        Orthography::Ottoman,
+    ] {
+        let prefs = AbjadPrefs {
+            orthography,
+            ..AbjadPrefs::default()
+        };
+
+        for letter in ('\u{0600}'..='\u{06FF}').chain('\u{0750}'..='\u{077F}') {
+            if letter.abjad_strict(prefs).is_ok_and(|value| value > 0) {
+                assert!(
+                    letter_label(letter, Romanization::Ijmes).is_some(),
+                    "{} has no label",
+                    letter.escape_unicode()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn breakdown_labels() {
+    let breakdown = "بهاء".abjad_breakdown(AbjadPrefs::default());
+    let symbols: Vec<&str> = breakdown
+        .iter()
+        .filter_map(|entry| entry.label(Romanization::Ijmes))
+        .map(|label| label.symbol)
+        .collect();
+
+    assert_eq!(symbols, ["b", "h", "ā", "ʾ"]);
+}
@@ -0,0 +1,60 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{Abjad, AbjadPrefs, SpanScan};
+
+const POEM: &str = "جان فدای دوست کن\nهمایون پادشاه از بام افتاد";
+
+#[test]
+fn scan() {
+    let prefs = AbjadPrefs::default();
+    let spans: Vec<_> = SpanScan::new(POEM, 962..=962, 8, prefs).collect();
+
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].text, "همایون پادشاه از بام افتاد");
+    assert_eq!(spans[0].words, 5);
+    assert_eq!(&POEM[spans[0].start..spans[0].end], spans[0].text);
+}
+
+#[test]
+fn scan_range() {
+    let prefs = AbjadPrefs::default();
+    let spans: Vec<_> = SpanScan::new(POEM, 400..=500, 3, prefs).collect();
+    let texts: Vec<&str> = spans.iter().map(|span| span.text).collect();
+
+    assert_eq!(
+        texts,
+        [
+            "دوست",
+            "کن\nهمایون پادشاه",
+            "همایون پادشاه",
+            "همایون پادشاه از",
+            "افتاد"
+        ]
+    );
+
+    for span in spans {
+        assert_eq!(span.text.abjad(prefs), span.value);
+    }
+}
+
+#[test]
+fn scan_max_words() {
+    let prefs = AbjadPrefs::default();
+
+    assert_eq!(SpanScan::new(POEM, 962..=962, 4, prefs).count(), 0);
+}
+
+#[test]
+fn scan_shaddah() {
+    let text = "قد تمّمته tapdancing";
+    let prefs = AbjadPrefs {
+        count_shaddah: true,
+        ..AbjadPrefs::default()
+    };
+
+    let spans: Vec<_> = SpanScan::new(text, 1_029..=1_029, 3, prefs).collect();
+
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].text, "قد تمّمته");
+}
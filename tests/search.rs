@@ -0,0 +1,70 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{AbjadPrefs, ChronogramSearch, SearchPrefs};
+
+const LEXICON: [&str; 8] = ["باغ", "دل", "گل", "بلبل", "جان", "عشق", "گل", "hello"];
+
+#[test]
+fn search() {
+    let search = ChronogramSearch::new(
+        LEXICON,
+        1_053,
+        AbjadPrefs::default(),
+        SearchPrefs::default(),
+    );
+    let found: Vec<Vec<&str>> = search.collect();
+
+    assert_eq!(found, [vec!["گل", "باغ"]]);
+}
+
+#[test]
+fn search_repeats() {
+    let prefs_no_repeats = SearchPrefs::default();
+    let prefs_repeats = SearchPrefs {
+        allow_repeats: true,
+        ..SearchPrefs::default()
+    };
+
+    let without = ChronogramSearch::new(LEXICON, 100, AbjadPrefs::default(), prefs_no_repeats);
+    let with: Vec<Vec<&str>> =
+        ChronogramSearch::new(LEXICON, 100, AbjadPrefs::default(), prefs_repeats).collect();
+
+    assert_eq!(without.count(), 0);
+    assert_eq!(with, [vec!["گل", "گل"]]);
+}
+
+#[test]
+fn search_max_words() {
+    let prefs_two = SearchPrefs {
+        max_words: 2,
+        ..SearchPrefs::default()
+    };
+    let prefs_three = SearchPrefs::default();
+
+    let two = ChronogramSearch::new(LEXICON, 1_101, AbjadPrefs::default(), prefs_two).count();
+    let three: Vec<Vec<&str>> =
+        ChronogramSearch::new(LEXICON, 1_101, AbjadPrefs::default(), prefs_three).collect();
+
+    assert_eq!(two, 0);
+    assert_eq!(three, [vec!["دل", "بلبل", "باغ"]]);
+}
+
+#[test]
+fn search_lazy() {
+    let lexicon = ["ا", "ب", "ج", "د", "ه", "و", "ز", "ح", "ط"];
+    let prefs = SearchPrefs {
+        max_words: 9,
+        allow_repeats: true,
+    };
+
+    let mut search = ChronogramSearch::new(lexicon, 10_000, AbjadPrefs::default(), prefs);
+
+    assert_eq!(search.next(), None);
+
+    let first = ChronogramSearch::new(lexicon, 10, AbjadPrefs::default(), prefs)
+        .next()
+        .unwrap();
+
+    assert_eq!(first, ["ا", "ا", "ا", "ا", "ا", "ا", "ا", "ا", "ب"]);
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "serde")]
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{
+    Abjad, AbjadError, AbjadPrefs, AbjadTable, CalculationMode, LetterOrder, NameSpelling, Preset,
+    Romanization,
+};
+use serde_json::json;
+
+#[test]
+fn prefs_round_trip() {
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Custom(AbjadTable::MAGHRIBI),
+        mode: CalculationMode::Malfuzi(NameSpelling::Hamzated),
+        ..Preset::OttomanTarih.prefs()
+    };
+
+    let serialized = serde_json::to_string(&prefs).unwrap();
+    let deserialized: AbjadPrefs = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, prefs);
+}
+
+#[test]
+fn prefs_names() {
+    let value = serde_json::to_value(Preset::PersianChronogram.prefs()).unwrap();
+
+    assert_eq!(value["ignore_lone_hamzah"], json!(true));
+    assert_eq!(value["letter_order"], json!("mashriqi"));
+    assert_eq!(value["orthography"], json!("persian"));
+    assert_eq!(value["mode"], json!("kabir"));
+
+    let malfuzi = CalculationMode::Malfuzi(NameSpelling::Hamzated);
+    let preset = Preset::PersianChronogram;
+
+    // Names are written as they are displayed, so they can be passed to the CLI
+    assert_eq!(
+        serde_json::to_value(malfuzi).unwrap(),
+        json!(malfuzi.to_string())
+    );
+    assert_eq!(
+        serde_json::to_value(preset).unwrap(),
+        json!("persian-chronogram")
+    );
+    assert_eq!(
+        serde_json::to_value(Romanization::AlaLc).unwrap(),
+        json!("ala-lc")
+    );
+    assert_eq!(
+        serde_json::from_value::<CalculationMode>(json!("malfuzi-hamzated")).unwrap(),
+        malfuzi
+    );
+    assert!(serde_json::from_value::<CalculationMode>(json!("akbar")).is_err());
+}
+
+#[test]
+fn table() {
+    let value = serde_json::to_value(AbjadTable::MAGHRIBI).unwrap();
+
+    assert_eq!(value.as_array().map(Vec::len), Some(28));
+    assert_eq!(value[14], json!(300));
+    assert_eq!(
+        serde_json::from_value::<AbjadTable>(value).unwrap(),
+        AbjadTable::MAGHRIBI
+    );
+}
+
+#[test]
+fn prefs_missing_fields() {
+    let prefs: AbjadPrefs =
+        serde_json::from_value(json!({ "count_shaddah": true, "letter_order": "maghribi" }))
+            .unwrap();
+
+    assert_eq!(
+        prefs,
+        AbjadPrefs {
+            count_shaddah: true,
+            letter_order: LetterOrder::Maghribi,
+            ..AbjadPrefs::default()
+        }
+    );
+}
+
+#[test]
+fn error() {
+    let error = "بهاt".abjad_strict(AbjadPrefs::default()).unwrap_err();
+    let value = serde_json::to_value(&error).unwrap();
+
+    assert_eq!(
+        value,
+        json!({
+            "unrecognized_character": { "character": "t", "byte_offset": 6, "char_index": 3 }
+        })
+    );
+
+    let deserialized: AbjadError = serde_json::from_value(value).unwrap();
+
+    assert_eq!(deserialized.to_string(), error.to_string());
+}
+
+#[test]
+fn breakdown() {
+    let breakdown = "بّ".abjad_breakdown(AbjadPrefs {
+        count_shaddah: true,
+        ..AbjadPrefs::default()
+    });
+    let value = serde_json::to_value(&breakdown).unwrap();
+
+    assert_eq!(value[1]["rule"], json!("shaddah"));
+    assert_eq!(value[1]["running_total"], json!(4));
+}
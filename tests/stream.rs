@@ -0,0 +1,112 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use std::io::{BufReader, Read};
+
+use abjad::{Abjad, AbjadAccumulator, AbjadError, AbjadPrefs};
+
+fn shaddah() -> AbjadPrefs {
+    AbjadPrefs {
+        count_shaddah: true,
+        ..AbjadPrefs::default()
+    }
+}
+
+// A reader that returns at most a few bytes at a time
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.0.len()).min(3);
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn chunks() {
+    let mut accumulator = AbjadAccumulator::new(shaddah());
+
+    accumulator.feed("محم").unwrap();
+    accumulator.feed("ّد علی").unwrap();
+
+    assert_eq!(accumulator.total(), 242);
+    assert_eq!(accumulator.finish().unwrap(), (242, Vec::new()));
+}
+
+#[test]
+fn split_bytes() {
+    let text = "محمّد abc علی";
+    let bytes = text.as_bytes();
+
+    for split in 0..bytes.len() {
+        let mut accumulator = AbjadAccumulator::new(shaddah());
+
+        accumulator.feed_bytes(&bytes[..split]).unwrap();
+        accumulator.feed_bytes(&bytes[split..]).unwrap();
+
+        let (total, errors) = accumulator.finish().unwrap();
+
+        assert_eq!(
+            (u32::try_from(total).unwrap(), errors),
+            text.abjad_collect_errors(shaddah())
+        );
+    }
+}
+
+#[test]
+fn reader() {
+    let text = "بهاء الدین محمّد عاملی\n".repeat(1000);
+    let mut accumulator = AbjadAccumulator::new(shaddah());
+
+    accumulator.feed_reader(Trickle(text.as_bytes())).unwrap();
+
+    assert_eq!(accumulator.total(), text.as_str().abjad_wide(shaddah()));
+    assert_eq!(accumulator.errors().len(), 1000);
+    assert_eq!(
+        accumulator.errors()[1].byte_offset,
+        2 * text.len() / 1000 - 1
+    );
+
+    let mut buffered = AbjadAccumulator::new(shaddah());
+    buffered
+        .feed_reader(BufReader::new(text.as_bytes()))
+        .unwrap();
+
+    assert_eq!(buffered.finish().unwrap(), accumulator.finish().unwrap());
+}
+
+#[test]
+fn invalid_utf8() {
+    let mut accumulator = AbjadAccumulator::new(AbjadPrefs::default());
+
+    let result = accumulator.feed_bytes(&[0xD8, 0xA8, 0xFF]);
+    assert!(matches!(
+        result,
+        Err(AbjadError::InvalidUtf8 { byte_offset: 2 })
+    ));
+
+    let mut truncated = AbjadAccumulator::new(AbjadPrefs::default());
+    truncated.feed_bytes(&[0xD8, 0xA8, 0xD8]).unwrap();
+
+    assert_eq!(truncated.total(), 2);
+    assert!(matches!(
+        truncated.finish(),
+        Err(AbjadError::InvalidUtf8 { byte_offset: 2 })
+    ));
+
+    let mut split = AbjadAccumulator::new(AbjadPrefs::default());
+    split.feed_bytes(&[0xD8, 0xA8, 0xD8]).unwrap();
+
+    assert!(matches!(
+        split.feed("ب"),
+        Err(AbjadError::InvalidUtf8 { byte_offset: 2 })
+    ));
+    assert_eq!(split.total(), 2);
+
+    let mut reader = AbjadAccumulator::new(AbjadPrefs::default());
+    let error = reader.feed_reader(&[0xD8, 0xFF][..]).unwrap_err();
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,92 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{
+    Abjad, AbjadError, AbjadPrefs, AbjadTable, CalculationMode, LetterOrder, LetterRule,
+    NameSpelling,
+};
+
+#[test]
+fn table_builtin() {
+    let input = "ابجد هوز حطي كلمن سعفص قرشت ثخذ ضظغ";
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Custom(AbjadTable::MAGHRIBI),
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!(LetterOrder::Maghribi.table(), AbjadTable::MAGHRIBI);
+    assert_eq!(AbjadTable::MAGHRIBI.value('ش'), Some(1_000));
+    assert_eq!(AbjadTable::MASHRIQI.value('پ'), Some(2));
+    assert_eq!(input.abjad_strict(prefs).unwrap(), 5_995);
+}
+
+#[test]
+fn table_custom() {
+    let table = AbjadTable::MASHRIQI.with_value('ا', 11).unwrap();
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Custom(table),
+        ..AbjadPrefs::default()
+    };
+
+    let entries = "آب".abjad_breakdown(prefs);
+
+    assert_eq!(entries[0].value, 11);
+    assert_eq!(entries[0].rule, LetterRule::AlifMaddah);
+    assert_eq!(entries[1].rule, LetterRule::Letter);
+    assert_eq!("بابا".abjad_breakdown(prefs)[1].rule, LetterRule::Custom);
+    assert_eq!(AbjadTable::MASHRIQI.with_value('پ', 3), None);
+}
+
+#[test]
+fn table_parse() {
+    let toml = "# Maghribi\n[values]\n\"س\" = 300\n\"ص\" = 60\n\"ش\" = 1_000\n\"ض\" = 90\n\"ظ\" = 800\n\"غ\" = 900\n";
+    let json = r#"{"س": 300, "ص": 60, "ش": 1000, "ض": 90, "ظ": 800, "غ": 900}"#;
+    let text = "س = 300\nص = 60\nش = 1000 # Maghribi\nض = 90\nظ = 800\nغ = 900 # ditto";
+
+    assert_eq!(toml.parse::<AbjadTable>().unwrap(), AbjadTable::MAGHRIBI);
+    assert_eq!(json.parse::<AbjadTable>().unwrap(), AbjadTable::MAGHRIBI);
+    assert_eq!(text.parse::<AbjadTable>().unwrap(), AbjadTable::MAGHRIBI);
+}
+
+#[test]
+fn table_round_trip() {
+    let written = AbjadTable::MAGHRIBI.to_string();
+
+    assert_eq!(written.lines().count(), 28);
+    assert_eq!(written.parse::<AbjadTable>().unwrap(), AbjadTable::MAGHRIBI);
+}
+
+#[test]
+fn table_invalid() {
+    let Err(AbjadError::InvalidTable { line, entry }) = "ا = 1\nپ = 2".parse::<AbjadTable>()
+    else {
+        panic!("expected an invalid table");
+    };
+
+    assert_eq!(line, 2);
+    assert_eq!(entry, "پ = 2");
+    assert!("ا = one".parse::<AbjadTable>().is_err());
+    assert!("ا".parse::<AbjadTable>().is_err());
+}
+
+#[test]
+fn table_extreme() {
+    let table = AbjadTable::MASHRIQI.with_value('ا', u32::MAX).unwrap();
+    let prefs = AbjadPrefs {
+        letter_order: LetterOrder::Custom(table),
+        double_alif_maddah: true,
+        fold_presentation_forms: true,
+        ..AbjadPrefs::default()
+    };
+
+    assert_eq!("آ".abjad(prefs), u32::MAX);
+    assert_eq!("\u{FEF5}".abjad(prefs), u32::MAX);
+    assert_eq!("اب".abjad_wide(prefs), u64::from(u32::MAX) + 2);
+
+    let malfuzi = AbjadPrefs {
+        mode: CalculationMode::Malfuzi(NameSpelling::Plain),
+        ..prefs
+    };
+
+    assert_eq!("ا".abjad(malfuzi), u32::MAX);
+}
@@ -0,0 +1,152 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{transliterate, Abjad, AbjadError, AbjadPrefs, Romanization};
+
+#[test]
+fn ijmes() {
+    let result = transliterate("Bahāʾ al-Dīn Muḥammad", Romanization::Ijmes).unwrap();
+
+    assert_eq!(result.arabic, "بهاء الدين محمّد");
+    assert!(result.ambiguities.is_empty());
+    assert_eq!((&result).abjad_strict(AbjadPrefs::default()).unwrap(), 196);
+}
+
+#[test]
+fn schemes_agree() {
+    let ijmes = transliterate("Rashīd al-Dīn al-Ṭūsī", Romanization::Ijmes).unwrap();
+    let ala_lc = transliterate("Rashīd al-Dīn al-Ṭūsī", Romanization::AlaLc).unwrap();
+    let dmg = transliterate("Rašīd ad-Dīn aṭ-Ṭūsī", Romanization::Dmg).unwrap();
+
+    assert_eq!(ijmes.arabic, "رشيد الدين الطوسي");
+    assert_eq!(ala_lc.arabic, ijmes.arabic);
+    assert_eq!(dmg.arabic, ijmes.arabic);
+}
+
+#[test]
+fn digraphs() {
+    let ijmes = transliterate("Rashīd", Romanization::Ijmes).unwrap();
+
+    assert_eq!(ijmes.ambiguities.len(), 1);
+    assert_eq!(ijmes.ambiguities[0].latin, "sh");
+    assert_eq!(ijmes.ambiguities[0].chosen, "ش");
+    assert_eq!(ijmes.ambiguities[0].alternatives, ["سه"]);
+
+    let ala_lc = transliterate("Asʹhal", Romanization::AlaLc).unwrap();
+
+    assert_eq!(ala_lc.arabic, "اسهل");
+    assert!(ala_lc.ambiguities.is_empty());
+    assert_eq!(
+        transliterate("Ashal", Romanization::Dmg).unwrap().arabic,
+        "اسهل"
+    );
+}
+
+#[test]
+fn vowels_and_doubling() {
+    let result = transliterate("ʿAbd Allāh Abū l-Faḍl Bashshār", Romanization::Ijmes).unwrap();
+
+    assert_eq!(result.arabic, "عبد الله ابو الفضل بشّار");
+    assert_eq!(result.ambiguities.len(), 2);
+}
+
+#[test]
+fn allah() {
+    let joined = transliterate("ʿAbdullāh Rūḥullāh Asadullāh", Romanization::Ijmes).unwrap();
+
+    assert_eq!(joined.arabic, "عبدالله روحالله اسدالله");
+    assert_eq!(
+        transliterate("ʿAbdullāh", Romanization::Ijmes)
+            .unwrap()
+            .abjad(AbjadPrefs::default()),
+        142
+    );
+
+    let lillah = transliterate("Lillāh li-llāh billāh", Romanization::Ijmes).unwrap();
+
+    assert_eq!(lillah.arabic, "لله لله بالله");
+    assert_eq!(
+        transliterate("billāhi lillāhi ʿAbdullāhi", Romanization::Ijmes)
+            .unwrap()
+            .arabic,
+        "بالله لله عبدالله"
+    );
+    assert_eq!(
+        transliterate("Lillāh", Romanization::Ijmes)
+            .unwrap()
+            .abjad(AbjadPrefs::default()),
+        65
+    );
+}
+
+#[test]
+fn medial_alif() {
+    let result = transliterate("ʿAbd al-Raḥmān", Romanization::Ijmes).unwrap();
+
+    assert_eq!(result.arabic, "عبد الرحمان");
+    assert_eq!(result.ambiguities.len(), 1);
+    assert_eq!(result.ambiguities[0].latin, "ā");
+    assert_eq!(result.ambiguities[0].alternatives, [""]);
+}
+
+#[test]
+fn endings() {
+    let ijmes = transliterate("Fāṭima Muṣṭafā", Romanization::Ijmes).unwrap();
+
+    assert_eq!(ijmes.arabic, "فاطمة مصطفا");
+    // The medial ā of Fāṭima comes first
+    assert_eq!(ijmes.ambiguities[1].alternatives, [""]);
+    assert_eq!(ijmes.ambiguities[2].byte_offset, 20);
+    assert_eq!(ijmes.ambiguities[2].alternatives, ["ى"]);
+
+    let ala_lc = transliterate("Fāṭimah Muṣṭafá", Romanization::AlaLc).unwrap();
+
+    assert_eq!(ala_lc.arabic, "فاطمة مصطفى");
+    assert_eq!(ala_lc.ambiguities.len(), 1);
+}
+
+#[test]
+fn final_ah() {
+    for romanization in [Romanization::Ijmes, Romanization::Dmg] {
+        let result = transliterate("dargah", romanization).unwrap();
+
+        assert_eq!(result.arabic, "درگة");
+        assert_eq!(result.ambiguities.len(), 1);
+        assert_eq!(result.ambiguities[0].latin, "ah");
+        assert_eq!(result.ambiguities[0].alternatives, ["ه"]);
+    }
+
+    assert!(transliterate("dargah", Romanization::AlaLc)
+        .unwrap()
+        .ambiguities
+        .is_empty());
+}
+
+#[test]
+fn hamzah() {
+    let result = transliterate("Qurʾān masʾala raʾīs muʾmin", Romanization::Ijmes).unwrap();
+
+    assert_eq!(result.arabic, "قرآن مسألة رئيس مؤمن");
+    assert_eq!(result.ambiguities.len(), 4);
+}
+
+#[test]
+fn combining_marks() {
+    let decomposed = transliterate("Mu\u{0068}\u{0323}ammad", Romanization::Ijmes).unwrap();
+
+    assert_eq!(decomposed.arabic, "محمّد");
+}
+
+#[test]
+fn unrecognized() {
+    let result = transliterate("Bahāʾ Xerxes", Romanization::Ijmes);
+
+    assert!(matches!(
+        result,
+        Err(AbjadError::UnrecognizedCharacter(error)) if error.byte_offset == 8 && error.char_index == 6
+    ));
+    assert_eq!(
+        "ala-lc".parse::<Romanization>().unwrap(),
+        Romanization::AlaLc
+    );
+}
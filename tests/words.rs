@@ -0,0 +1,51 @@
+#![forbid(unsafe_code)]
+#![warn(clippy::cargo, clippy::nursery, clippy::pedantic)]
+
+use abjad::{Abjad, AbjadPrefs, AbjadWords};
+
+#[test]
+fn words() {
+    let input = "عادت می‌کنیم";
+    let prefs = AbjadPrefs::default();
+
+    let words: Vec<_> = AbjadWords::new(input, prefs).collect();
+
+    assert_eq!(words.len(), 2);
+    assert_eq!(words[0].text, "عادت");
+    assert_eq!(words[0].value, 475);
+    assert_eq!(words[1].text, "می‌کنیم");
+    assert_eq!(words[1].start, 9);
+    assert_eq!(words[1].end, input.len());
+    assert_eq!(words[1].value, 170);
+}
+
+#[test]
+fn words_zwnj() {
+    let input = "عادت می‌کنیم";
+    let prefs = AbjadPrefs::default();
+
+    let texts: Vec<&str> = AbjadWords::new(input, prefs)
+        .split_on_zwnj(true)
+        .map(|word| word.text)
+        .collect();
+
+    assert_eq!(texts, ["عادت", "می", "کنیم"]);
+}
+
+#[test]
+fn words_errors() {
+    let input = "  روح الله\ttapdancing\nخمینی ";
+    let prefs = AbjadPrefs::default();
+
+    let words: Vec<_> = AbjadWords::new(input, prefs).collect();
+    let total: u32 = words.iter().map(|word| word.value).sum();
+
+    assert_eq!(words.len(), 4);
+    assert_eq!(total, input.abjad(prefs));
+    assert!(words[1].errors.is_empty());
+    assert_eq!(words[2].value, 0);
+    assert_eq!(words[2].errors.len(), 10);
+    assert_eq!(words[2].errors[0].byte_offset, 18);
+    assert_eq!(words[2].errors[0].char_index, 11);
+    assert_eq!(input[18..].chars().next(), Some('t'));
+}